@@ -0,0 +1,33 @@
+use std::env;
+use std::io;
+use std::path::Path;
+
+use aoc_2021::input::Input;
+use aoc_2021::solver::{Part, PUZZLES};
+
+const RUNS: usize = 10;
+
+/// Times every day over its fixture; `cargo bench -- 12 17` only times
+/// the given days.
+fn main() -> io::Result<()> {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let days = env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse::<usize>().ok())
+        .collect::<Vec<_>>();
+
+    for puzzle in PUZZLES
+        .iter()
+        .filter(|p| days.is_empty() || days.contains(&p.day))
+    {
+        let input = Input::file(fixtures.join(format!("{:02}.txt", puzzle.day)));
+        let lines = input.lines()?;
+        let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let timings = (puzzle.bench)(&lines, &Part::ALL, RUNS)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        print!("{}", timings);
+    }
+
+    Ok(())
+}
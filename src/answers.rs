@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use std::io;
+use toml::Value;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::input::Input;
+use crate::solver::{Part, Puzzle};
+
+/// The recorded answers to each puzzle, as kept in `fixtures/answers.toml`:
+///
+/// ```toml
+/// [01]
+/// part_1 = "1301"
+/// part_2 = "1346"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(usize, Part), String>,
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self> {
+        let manifest = s.parse::<Value>().map_err(|e| {
+            let line = e.line_col().map(|(line, _)| line).unwrap_or(0);
+            let err = Error::new(
+                ErrorKind::Malformed("an answers manifest"),
+                s.lines().nth(line).unwrap_or(""),
+            );
+
+            match e.line_col() {
+                Some((line, column)) => err.at_line(line + 1).at_column(column + 1),
+                None => err,
+            }
+        })?;
+        let mut answers = BTreeMap::new();
+
+        for (day, parts) in manifest.as_table().into_iter().flatten() {
+            let day = day
+                .parse::<usize>()
+                .map_err(|_| Error::new(ErrorKind::Malformed("a day number"), day))?;
+            let parts = parts.as_table().ok_or_else(|| {
+                Error::new(ErrorKind::Malformed("a table of answers"), "").on_day(day)
+            })?;
+
+            for (part, answer) in parts {
+                let part = match part.as_str() {
+                    "part_1" => Part::One,
+                    "part_2" => Part::Two,
+                    _ => return Err(Error::new(ErrorKind::UnrecognizedToken, part).on_day(day)),
+                };
+                let answer = match answer {
+                    Value::String(s) => s.clone(),
+                    Value::Integer(n) => n.to_string(),
+                    _ => {
+                        return Err(
+                            Error::new(ErrorKind::Malformed("a string or an integer"), "")
+                                .on_day(day),
+                        )
+                    }
+                };
+
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn load(input: &Input) -> io::Result<Self> {
+        let manifest = input.lines()?.join("\n");
+
+        Self::parse(&manifest).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn get(&self, day: usize, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    /// Solves one part of a puzzle and compares it with the recorded answer.
+    pub fn check(&self, puzzle: &Puzzle, lines: &[&str], part: Part) -> Check {
+        let actual = (puzzle.solve)(lines, part);
+        let outcome = match self.get(puzzle.day, part) {
+            Some(expected) if actual.as_deref() == Ok(expected) => Outcome::Correct,
+            Some(expected) => Outcome::Regressed {
+                expected: expected.to_string(),
+                actual,
+            },
+            None => Outcome::Unrecorded(actual),
+        };
+
+        Check {
+            day: puzzle.day,
+            part,
+            outcome,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Regressed {
+        expected: String,
+        actual: Result<String>,
+    },
+    Unrecorded(Result<String>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Check {
+    pub day: usize,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn is_regression(&self) -> bool {
+        matches!(self.outcome, Outcome::Regressed { .. })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver;
+
+    const MANIFEST: &str = "[01]
+part_1 = \"7\"
+part_2 = 5
+
+[13]
+part_2 = '''
+#.#
+.#.
+'''
+";
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(MANIFEST).expect("bad manifest");
+
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(1, Part::One), Some("7"));
+        assert_eq!(answers.get(1, Part::Two), Some("5"));
+        assert_eq!(answers.get(13, Part::One), None);
+        assert_eq!(answers.get(13, Part::Two), Some("#.#\n.#.\n"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Answers::parse("[01]\npart_1 = ").unwrap_err();
+        assert_eq!(err.line(), Some(2));
+
+        let err = Answers::parse("[01]\npart_3 = \"7\"").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.day()),
+            (&ErrorKind::UnrecognizedToken, Some(1))
+        );
+
+        let err = Answers::parse("[one]\npart_1 = \"7\"").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("a day number"));
+    }
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse("[01]\npart_1 = \"7\"\npart_2 = \"6\"").expect("bad manifest");
+        let puzzle = solver::puzzle(1).expect("no puzzle");
+        let lines = [
+            "199", "200", "208", "210", "200", "207", "240", "269", "260", "263",
+        ];
+
+        assert_eq!(
+            answers.check(puzzle, &lines, Part::One).outcome,
+            Outcome::Correct
+        );
+        assert_eq!(
+            answers.check(puzzle, &lines, Part::Two).outcome,
+            Outcome::Regressed {
+                expected: "6".to_string(),
+                actual: Ok("5".to_string())
+            }
+        );
+        assert_eq!(
+            Answers::default().check(puzzle, &lines, Part::One).outcome,
+            Outcome::Unrecorded(Ok("7".to_string()))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt::{self, Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::solver::{Part, Solver};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// The spread of a number of timed runs.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        Some(Self {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timings {
+    pub day: usize,
+    pub runs: usize,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (stage, stats) in &self.stages {
+            writeln!(
+                f,
+                "day {:02} {:<7} min {:>10.1?}  median {:>10.1?}  max {:>10.1?}",
+                self.day,
+                stage.to_string(),
+                stats.min,
+                stats.median,
+                stats.max
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Timings {
+    pub const CSV_HEADER: &'static str = "day,stage,runs,min_ns,median_ns,max_ns";
+
+    /// One line per stage, to go below `CSV_HEADER`.
+    pub fn to_csv(&self) -> String {
+        self.stages
+            .iter()
+            .map(|(stage, stats)| {
+                format!(
+                    "{},{},{},{},{},{}\n",
+                    self.day,
+                    stage,
+                    self.runs,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos()
+                )
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> String {
+        let stages = self
+            .stages
+            .iter()
+            .map(|(stage, stats)| {
+                format!(
+                    "{{\"stage\":\"{}\",\"min_ns\":{},\"median_ns\":{},\"max_ns\":{}}}",
+                    stage,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\"day\":{},\"runs\":{},\"stages\":[{}]}}",
+            self.day,
+            self.runs,
+            stages.join(",")
+        )
+    }
+}
+
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> Option<Stats> {
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(&samples)
+}
+
+/// Times parsing and each of the given parts separately, `runs` times each.
+pub fn bench<S: Solver>(lines: &[&str], parts: &[Part], runs: usize) -> Result<Timings> {
+    let input = S::parse(lines).map_err(|e| e.on_day(S::DAY))?;
+    let no_runs = || Error::new(ErrorKind::Malformed("at least one run"), "").on_day(S::DAY);
+    let mut stages = vec![(
+        Stage::Parse,
+        time(runs, || S::parse(lines)).ok_or_else(no_runs)?,
+    )];
+
+    for &part in parts {
+        let stats = match part {
+            Part::One => time(runs, || S::part_1(&input)),
+            Part::Two => time(runs, || S::part_2(&input)),
+        };
+
+        stages.push((Stage::Part(part), stats.ok_or_else(no_runs)?));
+    }
+
+    Ok(Timings {
+        day: S::DAY,
+        runs,
+        stages,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::Day01;
+
+    #[test]
+    fn stats() {
+        let samples = [3, 1, 4, 1, 5].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).expect("no samples");
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn bench_stages() {
+        let timings = bench::<Day01>(&["199", "200", "208"], &[Part::Two], 3).expect("no timings");
+        let stages = timings.stages.iter().map(|(s, _)| *s).collect::<Vec<_>>();
+
+        assert_eq!(timings.day, 1);
+        assert_eq!(stages, vec![Stage::Parse, Stage::Part(Part::Two)]);
+        assert!(bench::<Day01>(&["x"], &Part::ALL, 3).is_err());
+        assert!(bench::<Day01>(&["1"], &Part::ALL, 0).is_err());
+    }
+
+    #[test]
+    fn csv() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(42)]).expect("no samples");
+        let timings = Timings {
+            day: 7,
+            runs: 1,
+            stages: vec![(Stage::Parse, stats), (Stage::Part(Part::One), stats)],
+        };
+
+        assert_eq!(
+            timings.to_csv(),
+            "7,parse,1,42,42,42\n7,part 1,1,42,42,42\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(42)]).expect("no samples");
+        let timings = Timings {
+            day: 7,
+            runs: 1,
+            stages: vec![(Stage::Parse, stats)],
+        };
+
+        assert_eq!(
+            timings.to_json(),
+            "{\"day\":7,\"runs\":1,\"stages\":[{\"stage\":\"parse\",\"min_ns\":42,\"median_ns\":42,\"max_ns\":42}]}"
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process;
+
+use aoc_2021::answers::{Answers, Outcome};
+use aoc_2021::bench::Timings;
+use aoc_2021::bingo_odds;
+use aoc_2021::day01::Day01;
+use aoc_2021::day04::{parse_bingo, Day04};
+use aoc_2021::error::Result;
+use aoc_2021::filter::Filter;
+use aoc_2021::input::Input;
+use aoc_2021::output::{Format, Output, Record};
+use aoc_2021::solver::{self, Part, Puzzle, Solver};
+
+const USAGE: &str = "usage: aoc run <day|all> [--part <1|2>] [--input <path>]
+                        [--format <text|json|csv>] [--bench [--runs <n>]]
+                        [--filter <filter>]
+       aoc verify [--part <1|2>] [--input <path>]
+       aoc odds [--input <path>] [--games <n>] [--seed <n> | --exact]
+
+  <day>           run a single day, reading the puzzle input from stdin
+  all             run every day, reading `<path>/NN.txt` or `<path>/NN.txt.gz`
+                  (default: fixtures)
+  verify          run every day like `all` and compare the answers with the
+                  ones recorded in `<path>/answers.toml`
+  odds            estimate how likely each day 4 board is to win if the
+                  numbers were drawn in a random order, and on which draw the
+                  games it wins are won on average
+  --part <1|2>    only run the given part
+  --input <path>  read the puzzle input from a file, gzip-compressed if it ends
+                  in `.gz` (or a directory for `all`)
+  --format <fmt>  print results as `text` (default), `json` or `csv`, the
+                  latter two with the time taken and any error
+  --bench         time parsing and each part instead of printing the answers
+  --runs <n>      how many times to run each stage when timing (default: 10)
+  --filter <f>    smooth the sonar sweep for day 1 part 2 with `sum:<width>`
+                  (default: sum:3), `mean:<width>`, `median:<width>` or
+                  `ema:<alpha>`
+  --games <n>     how many random games to play for `odds` (default: 10000)
+  --seed <n>      the seed for shuffling the draw for `odds` (default: 2021)
+  --exact         play every order of the draw for `odds` instead, which only
+                  works for at most 10 distinct numbers";
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Verify,
+    Odds,
+}
+
+struct Options {
+    command: Command,
+    puzzles: Vec<&'static Puzzle>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    all: bool,
+    bench: bool,
+    runs: usize,
+    format: Format,
+    filter: Option<Filter>,
+    games: Option<usize>,
+    seed: Option<u64>,
+    exact: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+
+    let command = match args.next().map(|s| s.as_str()) {
+        Some("run") => Command::Run,
+        Some("verify") => Command::Verify,
+        Some("odds") => Command::Odds,
+        Some(other) => return Err(format!("unrecognized command `{}`", other)),
+        None => return Err("missing command".to_string()),
+    };
+
+    let day = match command {
+        Command::Run => args.next().map(|s| s.as_str()),
+        Command::Verify => Some("all"),
+        Command::Odds => Some("4"),
+    };
+    let (puzzles, all) = match day {
+        Some("all") => (solver::PUZZLES.iter().collect::<Vec<_>>(), true),
+        Some(day) => {
+            let puzzle = day
+                .parse::<usize>()
+                .ok()
+                .and_then(solver::puzzle)
+                .ok_or_else(|| format!("no solver for day `{}`", day))?;
+
+            (vec![puzzle], false)
+        }
+        None => return Err("missing day".to_string()),
+    };
+    let mut options = Options {
+        command,
+        puzzles,
+        parts: Part::ALL.to_vec(),
+        input: None,
+        all,
+        bench: false,
+        runs: 10,
+        format: Format::Text,
+        filter: None,
+        games: None,
+        seed: None,
+        exact: false,
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{}`", flag))
+        };
+
+        match flag.as_str() {
+            "--part" => {
+                let part = value()?
+                    .parse::<Part>()
+                    .map_err(|part| format!("no part `{}`", part))?;
+
+                options.parts = vec![part];
+            }
+            "--input" => options.input = Some(value()?.to_string()),
+            "--bench" => options.bench = true,
+            "--runs" => {
+                let runs = value()?;
+
+                options.runs = runs
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("no number of runs `{}`", runs))?;
+            }
+            "--format" => {
+                options.format = value()?
+                    .parse::<Format>()
+                    .map_err(|format| format!("no format `{}`", format))?;
+            }
+            "--filter" => {
+                let filter = value()?
+                    .parse::<Filter>()
+                    .map_err(|filter| format!("no filter `{}`", filter))?;
+
+                options.filter = Some(filter);
+            }
+            "--games" => {
+                let games = value()?;
+
+                options.games = Some(
+                    games
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| format!("no number of games `{}`", games))?,
+                );
+            }
+            "--seed" => {
+                let seed = value()?;
+
+                options.seed = Some(
+                    seed.parse::<u64>()
+                        .map_err(|_| format!("no seed `{}`", seed))?,
+                );
+            }
+            "--exact" => options.exact = true,
+            _ => return Err(format!("unrecognized option `{}`", flag)),
+        }
+    }
+
+    if options.filter.is_some() {
+        if options.command != Command::Run || options.bench {
+            return Err("`--filter` only applies to running the answers".to_string());
+        } else if options
+            .puzzles
+            .iter()
+            .all(|puzzle| puzzle.day != Day01::DAY)
+        {
+            return Err("`--filter` only applies to day 1".to_string());
+        }
+    }
+
+    let sampled = options.games.is_some() || options.seed.is_some();
+    if (sampled || options.exact) && options.command != Command::Odds {
+        return Err("`--games`, `--seed` and `--exact` only apply to `odds`".to_string());
+    } else if sampled && options.exact {
+        return Err("`--exact` plays every order, so takes no `--games` or `--seed`".to_string());
+    }
+
+    Ok(options)
+}
+
+fn input_for(options: &Options, puzzle: &Puzzle) -> Input {
+    if options.all {
+        let dir = Path::new(options.input.as_deref().unwrap_or("fixtures"));
+        let path = dir.join(format!("{:02}.txt", puzzle.day));
+
+        if path.exists() {
+            Input::file(path)
+        } else {
+            Input::gzip_file(path.with_extension("txt.gz"))
+        }
+    } else if let Some(path) = &options.input {
+        Input::from_path(path)
+    } else {
+        Input::stdin()
+    }
+}
+
+fn solve(options: &Options, puzzle: &Puzzle, lines: &[&str], part: Part) -> Result<String> {
+    match options.filter {
+        Some(filter) if puzzle.day == Day01::DAY && part == Part::Two => {
+            Day01::solve_filtered(lines, filter)
+        }
+        _ => (puzzle.solve)(lines, part),
+    }
+}
+
+/// Prints the bare answers to a single day, as its own binary would.
+fn run_bare(options: &Options, puzzle: &Puzzle) -> io::Result<bool> {
+    let mut all_solved = true;
+    let lines = input_for(options, puzzle).lines()?;
+    let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+
+    for &part in &options.parts {
+        match solve(options, puzzle, &lines, part) {
+            Ok(answer) => println!("{}", answer),
+            Err(err) => {
+                eprintln!("aoc: part {}: {}", part, err);
+                all_solved = false;
+            }
+        }
+    }
+
+    Ok(all_solved)
+}
+
+fn run(options: &Options) -> io::Result<bool> {
+    if let (Format::Text, false, [puzzle]) = (options.format, options.all, &options.puzzles[..]) {
+        return run_bare(options, puzzle);
+    }
+
+    let mut all_solved = true;
+    let mut output = Output::new(options.format, io::stdout().lock())?;
+
+    for puzzle in &options.puzzles {
+        let lines = input_for(options, puzzle).lines()?;
+        let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+
+        for &part in &options.parts {
+            let record = Record::time(puzzle.day, part, || solve(options, puzzle, &lines, part));
+
+            all_solved &= record.answer.is_ok();
+            output.write(&record)?;
+        }
+    }
+
+    drop(output.finish()?);
+    Ok(all_solved)
+}
+
+fn bench(options: &Options) -> io::Result<bool> {
+    let mut all_timed = true;
+    let mut json = vec![];
+
+    if options.format == Format::Csv {
+        println!("{}", Timings::CSV_HEADER);
+    }
+
+    for puzzle in &options.puzzles {
+        let lines = input_for(options, puzzle).lines()?;
+        let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+
+        match (puzzle.bench)(&lines, &options.parts, options.runs) {
+            Ok(timings) => match options.format {
+                Format::Text => print!("{}", timings),
+                Format::Json => json.push(timings.to_json()),
+                Format::Csv => print!("{}", timings.to_csv()),
+            },
+            Err(err) => {
+                eprintln!("aoc: {}", err);
+                all_timed = false;
+            }
+        }
+    }
+
+    if options.format == Format::Json {
+        println!("[{}]", json.join(",\n"));
+    }
+
+    Ok(all_timed)
+}
+
+/// Prints each board's chance of winning a game with the draw shuffled, and
+/// the draw on which the games it wins are won on average.
+fn odds(options: &Options) -> io::Result<bool> {
+    let lines = input_for(options, options.puzzles[0]).lines()?;
+    let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    let (draw, boards) = match parse_bingo(&lines) {
+        Ok(bingo) => bingo,
+        Err(err) => {
+            eprintln!("aoc: {}", err.on_day(Day04::DAY));
+            return Ok(false);
+        }
+    };
+
+    let mut pool = vec![];
+    for number in draw {
+        if !pool.contains(&number) {
+            pool.push(number);
+        }
+    }
+
+    let estimate = if options.exact {
+        match bingo_odds::exact(&pool, &boards) {
+            Some(odds) => odds,
+            None => {
+                eprintln!(
+                    "aoc: `--exact` works for at most {} numbers, not {}",
+                    bingo_odds::MAX_EXACT,
+                    pool.len()
+                );
+                return Ok(false);
+            }
+        }
+    } else {
+        let games = options.games.unwrap_or(10_000);
+
+        bingo_odds::monte_carlo(&pool, &boards, games, options.seed.unwrap_or(2021))
+    };
+    let draw = |turn: Option<f64>| turn.map_or("never".to_string(), |t| format!("{:.2}", t));
+
+    for (i, odds) in estimate.boards.iter().enumerate() {
+        println!(
+            "board {}: wins {:.4}, on draw {} when it does",
+            i + 1,
+            odds.win_probability,
+            draw(odds.expected_turn)
+        );
+    }
+
+    match estimate.favourite() {
+        Some(i) => println!(
+            "favourite: board {} over {} games, won on draw {} on average",
+            i + 1,
+            estimate.games,
+            draw(estimate.winning_turn)
+        ),
+        None => println!("no board can win"),
+    }
+
+    Ok(true)
+}
+
+fn verify(options: &Options) -> io::Result<bool> {
+    let dir = Path::new(options.input.as_deref().unwrap_or("fixtures"));
+    let answers = Answers::load(&Input::file(dir.join("answers.toml")))?;
+    let mut regressed = vec![];
+
+    for puzzle in &options.puzzles {
+        let lines = input_for(options, puzzle).lines()?;
+        let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+
+        for &part in &options.parts {
+            let check = answers.check(puzzle, &lines, part);
+
+            match &check.outcome {
+                Outcome::Correct => println!("day {:02} part {}: ok", check.day, part),
+                Outcome::Regressed { expected, actual } => {
+                    let actual = match actual {
+                        Ok(answer) => format!("{:?}", answer),
+                        Err(err) => err.to_string(),
+                    };
+
+                    println!(
+                        "day {:02} part {}: expected {:?}, got {}",
+                        check.day, part, expected, actual
+                    );
+                }
+                Outcome::Unrecorded(_) => {
+                    println!("day {:02} part {}: no recorded answer", check.day, part)
+                }
+            }
+
+            if check.is_regression() && !regressed.contains(&check.day) {
+                regressed.push(check.day);
+            }
+        }
+    }
+
+    if !regressed.is_empty() {
+        let days = regressed
+            .iter()
+            .map(|day| format!("{:02}", day))
+            .collect::<Vec<_>>();
+
+        eprintln!("aoc: regressed on day {}", days.join(", "));
+    }
+
+    Ok(regressed.is_empty())
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(reason) => {
+            eprintln!("aoc: {}\n\n{}", reason, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match options.command {
+        Command::Run if options.bench => bench(&options),
+        Command::Run => run(&options),
+        Command::Verify => verify(&options),
+        Command::Odds => odds(&options),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("aoc: {}", err);
+            process::exit(1);
+        }
+    }
+}
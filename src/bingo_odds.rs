@@ -0,0 +1,358 @@
+use crate::day04::{simulate, Board};
+use crate::rng::Rng;
+
+use std::collections::HashMap;
+
+/// The most numbers `exact` will try every order of.
+pub const MAX_EXACT: usize = 10;
+
+/// The most winning lines `turns` will take every combination of.
+pub const MAX_LINES: usize = 20;
+
+/// How one board fares over many games.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BoardOdds {
+    /// The chance of winning the game, sharing it with any boards that win
+    /// on the same number.
+    pub win_probability: f64,
+    /// The average draw on which the game is won, in the games this board
+    /// wins, weighting each by the board's share of it.
+    pub expected_turn: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Odds {
+    pub games: usize,
+    pub boards: Vec<BoardOdds>,
+    /// The average draw on which the game is won, in the games some board
+    /// wins.
+    pub winning_turn: Option<f64>,
+}
+
+impl Odds {
+    /// The board most likely to win, if any can.
+    pub fn favourite(&self) -> Option<usize> {
+        self.boards
+            .iter()
+            .enumerate()
+            .filter(|(_, odds)| odds.win_probability > 0.0)
+            .max_by(|(_, a), (_, b)| a.win_probability.total_cmp(&b.win_probability))
+            .map(|(i, _)| i)
+    }
+}
+
+struct Tally {
+    games: usize,
+    wins: Vec<f64>,
+    turns: Vec<f64>,
+    won: (usize, usize),
+}
+
+impl Tally {
+    fn new(boards: usize) -> Self {
+        Self {
+            games: 0,
+            wins: vec![0.0; boards],
+            turns: vec![0.0; boards],
+            won: (0, 0),
+        }
+    }
+
+    fn play(&mut self, draw: &[usize], boards: &[Board]) {
+        let wins = simulate(draw, &mut boards.to_vec());
+        let first_turn = wins.first().map(|win| win.turn);
+        let winners = wins
+            .iter()
+            .filter(|win| Some(win.turn) == first_turn)
+            .count();
+
+        for win in wins.iter().filter(|win| Some(win.turn) == first_turn) {
+            let share = 1.0 / winners as f64;
+
+            self.wins[win.board] += share;
+            self.turns[win.board] += share * (win.turn + 1) as f64;
+        }
+
+        if let Some(turn) = first_turn {
+            self.won.0 += turn + 1;
+            self.won.1 += 1;
+        }
+
+        self.games += 1;
+    }
+
+    fn odds(self) -> Odds {
+        let games = self.games;
+        let boards = self
+            .wins
+            .iter()
+            .zip(&self.turns)
+            .map(|(&wins, &turns)| BoardOdds {
+                win_probability: if games > 0 { wins / games as f64 } else { 0.0 },
+                expected_turn: Some(turns / wins).filter(|_| wins > 0.0),
+            })
+            .collect();
+        let (turns, won) = self.won;
+
+        Odds {
+            games,
+            boards,
+            winning_turn: Some(turns as f64 / won as f64).filter(|_| won > 0),
+        }
+    }
+}
+
+/// Estimates the odds by playing `games` games, each drawing every number
+/// in `pool` in a random order picked by an RNG seeded with `seed`.
+pub fn monte_carlo(pool: &[usize], boards: &[Board], games: usize, seed: u64) -> Odds {
+    let mut rng = Rng::new(seed);
+    let mut draw = pool.to_vec();
+    let mut tally = Tally::new(boards.len());
+
+    for _ in 0..games {
+        rng.shuffle(&mut draw);
+        tally.play(&draw, boards);
+    }
+
+    tally.odds()
+}
+
+/// Works out the odds by playing every order of the numbers in `pool`, or
+/// returns `None` if there are more than `MAX_EXACT` of them. This takes
+/// factorial time, so it is only good as an oracle to test the other modes
+/// against on tiny pools.
+pub fn exact(pool: &[usize], boards: &[Board]) -> Option<Odds> {
+    if pool.len() > MAX_EXACT {
+        return None;
+    }
+
+    let mut draw = pool.to_vec();
+    let mut tally = Tally::new(boards.len());
+    let mut counters = vec![0; draw.len()];
+    let mut i = 0;
+
+    // Heap's algorithm, visiting each permutation once.
+    tally.play(&draw, boards);
+    while i < draw.len() {
+        if counters[i] < i {
+            draw.swap(if i % 2 == 0 { 0 } else { counters[i] }, i);
+            tally.play(&draw, boards);
+            counters[i] += 1;
+            i = 0;
+        } else {
+            counters[i] = 0;
+            i += 1;
+        }
+    }
+
+    Some(tally.odds())
+}
+
+/// The chance of `board` getting bingo on each draw, the first one first,
+/// when every number in `pool` is drawn once in a random order and no other
+/// board is playing.
+///
+/// Counts the orders by inclusion–exclusion over the board's winning lines,
+/// so it works for full-sized games, but returns `None` if the board has more
+/// than `MAX_LINES` lines that can be completed from the pool.
+pub fn turns(pool: &[usize], board: &Board) -> Option<Vec<f64>> {
+    let index = pool
+        .iter()
+        .enumerate()
+        .map(|(i, &n)| (n, i))
+        .collect::<HashMap<_, _>>();
+    let lines = board
+        .lines()
+        .iter()
+        .filter_map(|line| line.iter().map(|n| index.get(n).copied()).collect())
+        .collect::<Vec<Vec<usize>>>();
+
+    if lines.len() > MAX_LINES {
+        return None;
+    }
+
+    // The sum of (-1)^(|S| + 1) over the non-empty sets S of lines covering
+    // `k` numbers between them, by `k`.
+    let mut signs = vec![0_i64; pool.len() + 1];
+    let mut covered = vec![0; pool.len()];
+    include_lines(&lines, &mut covered, 0, -1, &mut signs);
+
+    // The chance that all of `k` given numbers are among the first `t` drawn.
+    let all_drawn = |k: usize, t: usize| {
+        (0..k).fold(1.0, |p, i| {
+            p * t.saturating_sub(i) as f64 / (pool.len() - i) as f64
+        })
+    };
+    let by_turn = (0..=pool.len())
+        .map(|t| {
+            signs
+                .iter()
+                .enumerate()
+                .map(|(k, &sign)| sign as f64 * all_drawn(k, t))
+                .sum::<f64>()
+        })
+        .collect::<Vec<_>>();
+
+    Some(by_turn.windows(2).map(|w| w[1] - w[0]).collect())
+}
+
+/// Adds the sign of every non-empty set of `lines` joined to the lines
+/// already chosen, which cover `union` numbers, marked in `covered`, and
+/// have `sign` between them.
+fn include_lines(
+    lines: &[Vec<usize>],
+    covered: &mut [usize],
+    union: usize,
+    sign: i64,
+    signs: &mut [i64],
+) {
+    for (i, line) in lines.iter().enumerate() {
+        let mut union = union;
+
+        for &n in line {
+            if covered[n] == 0 {
+                union += 1;
+            }
+            covered[n] += 1;
+        }
+
+        signs[union] -= sign;
+        include_lines(&lines[i + 1..], covered, union, -sign, signs);
+
+        for &n in line {
+            covered[n] -= 1;
+        }
+    }
+}
+
+/// The average draw on which a board playing alone gets bingo, given the
+/// chance of it happening on each draw, or `None` if it never does.
+pub fn expected_turn(turns: &[f64]) -> Option<f64> {
+    let total = turns.iter().sum::<f64>();
+
+    Some(
+        turns
+            .iter()
+            .enumerate()
+            .map(|(t, p)| (t + 1) as f64 * p)
+            .sum::<f64>()
+            / total,
+    )
+    .filter(|_| total > 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day04::Pattern;
+    use crate::grid::Grid;
+
+    fn board(rows: Vec<Vec<usize>>) -> Board {
+        Board::new(Grid::from_rows(rows).expect("no grid"))
+    }
+
+    #[test]
+    fn exact_odds() {
+        let boards = [
+            board(vec![vec![1]]),
+            board(vec![vec![2, 3]]).with_patterns(&[Pattern::Full]),
+            board(vec![vec![9]]),
+        ];
+        let odds = exact(&[1, 2, 3], &boards).expect("too many numbers");
+
+        assert_eq!(odds.games, 6);
+        assert_eq!(odds.boards[0].win_probability, 4.0 / 6.0);
+        assert_eq!(odds.boards[0].expected_turn, Some(1.5));
+        assert_eq!(odds.boards[1].win_probability, 2.0 / 6.0);
+        assert_eq!(odds.boards[1].expected_turn, Some(2.0));
+        assert_eq!(odds.boards[2].win_probability, 0.0);
+        assert_eq!(odds.boards[2].expected_turn, None);
+        assert_eq!(odds.winning_turn, Some(10.0 / 6.0));
+        assert_eq!(odds.favourite(), Some(0));
+        assert_eq!(exact(&[0; 11], &boards), None);
+    }
+
+    #[test]
+    fn ties() {
+        let boards = [board(vec![vec![1]]), board(vec![vec![1]])];
+        let odds = exact(&[1, 2], &boards).expect("too many numbers");
+
+        assert_eq!(odds.boards[0].win_probability, 0.5);
+        assert_eq!(odds.boards[1].win_probability, 0.5);
+        assert_eq!(odds.boards[0].expected_turn, Some(1.5));
+        assert_eq!(odds.winning_turn, Some(1.5));
+    }
+
+    #[test]
+    fn turn_odds() {
+        let one = turns(&[1, 2, 3], &board(vec![vec![1]])).expect("too many lines");
+        let both = board(vec![vec![2, 3]]).with_patterns(&[Pattern::Full]);
+        let both = turns(&[1, 2, 3], &both).expect("too many lines");
+
+        assert_close(&one, &[1.0 / 3.0; 3]);
+        assert_close(&both, &[0.0, 1.0 / 3.0, 2.0 / 3.0]);
+        assert_eq!(expected_turn(&both).map(|t| (t * 3.0).round()), Some(8.0));
+        assert_eq!(turns(&[1, 2], &board(vec![vec![9]])), Some(vec![0.0, 0.0]));
+        assert_eq!(expected_turn(&[0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn turns_match_exact() {
+        let pool = [1, 2, 3, 4, 5, 6, 7, 8];
+        let boards = [
+            board(vec![vec![1, 2], vec![3, 4]]),
+            board(vec![vec![5, 1, 6], vec![2, 7, 9]]),
+            board(vec![vec![8, 1], vec![3, 6]])
+                .with_patterns(&[Pattern::Diagonals, Pattern::Corners]),
+        ];
+        // Playing alone, a board wins exactly when it gets bingo.
+        for board in &boards {
+            let odds = exact(&pool, std::slice::from_ref(board)).expect("too many numbers");
+            let expected = expected_turn(&turns(&pool, board).expect("too many lines"));
+
+            match (expected, odds.boards[0].expected_turn) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 1e-9, "{} != {}", a, b),
+                (a, b) => assert_eq!(a, b),
+            }
+        }
+    }
+
+    #[test]
+    fn full_game() {
+        let pool = (0..100).collect::<Vec<_>>();
+        let numbers = Grid::from_fn(5, 5, |x, y| 7 * (5 * y + x) % 100);
+        let turns = turns(&pool, &Board::new(numbers)).expect("too many lines");
+
+        assert_eq!(turns.len(), 100);
+        assert!((turns.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(turns[..4].iter().all(|&p| p.abs() < 1e-12));
+        assert!(expected_turn(&turns).expect("never wins") < 100.0);
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(a, b)| (a - b).abs() < 1e-12),
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn estimate() {
+        let boards = [
+            board(vec![vec![1]]),
+            board(vec![vec![2, 3]]).with_patterns(&[Pattern::Full]),
+        ];
+        let odds = monte_carlo(&[1, 2, 3], &boards, 10_000, 2021);
+
+        assert_eq!(odds, monte_carlo(&[1, 2, 3], &boards, 10_000, 2021));
+        assert!((odds.boards[0].win_probability - 4.0 / 6.0).abs() < 0.02);
+        assert!((odds.boards[0].expected_turn.expect("never won") - 1.5).abs() < 0.05);
+        assert!((odds.winning_turn.expect("never won") - 10.0 / 6.0).abs() < 0.05);
+    }
+}
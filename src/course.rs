@@ -0,0 +1,197 @@
+use crate::submarine::{Submarine, SubmarineV1, SubmarineV2};
+use crate::submarine_command::Command;
+
+/// A submarine model that knows which courses could take it from the
+/// surface to a given position.
+pub trait Planner: Submarine + Default {
+    /// Courses that may end at `(horizontal, depth)`, shortest first.
+    fn candidates(horizontal: isize, depth: isize) -> Vec<Vec<Command>>;
+}
+
+impl Planner for SubmarineV1 {
+    fn candidates(horizontal: isize, depth: isize) -> Vec<Vec<Command>> {
+        vec![course(&[forward(horizontal), down(depth)])]
+    }
+}
+
+impl Planner for SubmarineV2 {
+    /// Leaves out the courses whose amounts would not fit in an `isize`.
+    fn candidates(horizontal: isize, depth: isize) -> Vec<Vec<Command>> {
+        let mut candidates = vec![course(&[forward(horizontal)])];
+
+        if depth.checked_rem(horizontal) == Some(0) {
+            if let Some(aim) = depth.checked_div(horizontal) {
+                candidates.push(course(&[down(aim), forward(horizontal)]));
+            }
+        }
+
+        // The last step of one makes up the whole depth, whatever the rest
+        // of the way forward is.
+        for step in [1, -1] {
+            if let (Some(rest), Some(aim)) = (horizontal.checked_sub(step), depth.checked_mul(step))
+            {
+                candidates.push(course(&[forward(rest), down(aim), forward(step)]));
+            }
+        }
+
+        candidates.sort_by_key(|course| course.len());
+        candidates
+    }
+}
+
+/// The shortest course from the surface to `(horizontal, depth)`, checked by
+/// following it.
+pub fn plan<S: Planner>(horizontal: isize, depth: isize) -> Option<Vec<Command>> {
+    S::candidates(horizontal, depth)
+        .into_iter()
+        .find(|course| reaches::<S>(course, horizontal, depth))
+}
+
+/// Whether following `course` from the surface ends at `(horizontal, depth)`.
+pub fn reaches<S: Submarine + Default>(
+    course: &[Command],
+    horizontal: isize,
+    depth: isize,
+) -> bool {
+    let mut sub = S::default();
+
+    for command in course {
+        sub.apply(command);
+    }
+
+    (sub.horizontal(), sub.depth()) == (horizontal, depth)
+}
+
+/// Moves by `amount`, backwards if it is negative and can be turned round.
+fn forward(amount: isize) -> Command {
+    match amount.checked_neg() {
+        Some(back) if amount < 0 => Command::Back(back),
+        _ => Command::Forward(amount),
+    }
+}
+
+fn down(amount: isize) -> Command {
+    match amount.checked_neg() {
+        Some(up) if amount < 0 => Command::Up(up),
+        _ => Command::Down(amount),
+    }
+}
+
+/// Leaves out the commands that would not do anything.
+fn course(commands: &[Command]) -> Vec<Command> {
+    commands
+        .iter()
+        .copied()
+        .filter(|command| {
+            !matches!(
+                command,
+                Command::Forward(0) | Command::Back(0) | Command::Down(0) | Command::Up(0)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every course of `len` commands moving by at most `max` at a time.
+    fn courses(len: usize, max: isize) -> Vec<Vec<Command>> {
+        let commands = (-max..=max)
+            .flat_map(|n| [Command::Forward(n), Command::Down(n)])
+            .collect::<Vec<_>>();
+
+        (0..len).fold(vec![vec![]], |courses, _| {
+            courses
+                .iter()
+                .flat_map(|course| {
+                    commands.iter().map(move |&command| {
+                        let mut course = course.clone();
+                        course.push(command);
+                        course
+                    })
+                })
+                .collect()
+        })
+    }
+
+    fn assert_shortest<S: Planner>() {
+        let shorter = (0..=2).flat_map(|len| courses(len, 8)).collect::<Vec<_>>();
+
+        for horizontal in -4..=4 {
+            for depth in -4..=4 {
+                let plan = plan::<S>(horizontal, depth).expect("no plan");
+
+                assert!(reaches::<S>(&plan, horizontal, depth));
+                assert!(
+                    !shorter
+                        .iter()
+                        .filter(|course| course.len() < plan.len())
+                        .any(|course| reaches::<S>(course, horizontal, depth)),
+                    "a shorter course reaches ({}, {})",
+                    horizontal,
+                    depth
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn _02_plan() {
+        assert_eq!(
+            plan::<SubmarineV1>(15, 10),
+            Some(vec![Command::Forward(15), Command::Down(10)])
+        );
+        assert_eq!(
+            plan::<SubmarineV2>(15, 60),
+            Some(vec![Command::Down(4), Command::Forward(15)])
+        );
+        assert_eq!(
+            plan::<SubmarineV2>(15, 61),
+            Some(vec![
+                Command::Forward(14),
+                Command::Down(61),
+                Command::Forward(1)
+            ])
+        );
+        assert_eq!(
+            plan::<SubmarineV2>(0, -3),
+            Some(vec![Command::Back(1), Command::Up(3), Command::Forward(1)])
+        );
+        assert_eq!(plan::<SubmarineV2>(0, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn extremes() {
+        for &horizontal in &[isize::MIN, isize::MIN + 1, -1, 0, 1, isize::MAX] {
+            for &depth in &[isize::MIN, -1, 0, 1, isize::MAX] {
+                if let Some(plan) = plan::<SubmarineV1>(horizontal, depth) {
+                    assert!(reaches::<SubmarineV1>(&plan, horizontal, depth));
+                }
+
+                if let Some(plan) = plan::<SubmarineV2>(horizontal, depth) {
+                    assert!(reaches::<SubmarineV2>(&plan, horizontal, depth));
+                }
+            }
+        }
+
+        assert_eq!(
+            plan::<SubmarineV1>(isize::MIN, 0),
+            Some(vec![Command::Forward(isize::MIN)])
+        );
+        assert_eq!(
+            plan::<SubmarineV2>(isize::MIN, isize::MAX),
+            Some(vec![
+                Command::Back(isize::MAX),
+                Command::Up(isize::MAX),
+                Command::Back(1)
+            ])
+        );
+    }
+
+    #[test]
+    fn shortest() {
+        assert_shortest::<SubmarineV1>();
+        assert_shortest::<SubmarineV2>();
+    }
+}
@@ -0,0 +1,194 @@
+use std::cmp::Ordering;
+
+use crate::error::{parse_lines, parse_number, Result};
+use crate::filter::Filter;
+use crate::ordering_iter::*;
+use crate::solver::Solver;
+use crate::windowed::Windowed;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: usize = 1;
+
+    type Input = Vec<isize>;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        parse_lines(lines, parse_number::<isize>)
+    }
+
+    fn part_1(sonar_sweep: &Self::Input) -> Option<String> {
+        count_increases(sonar_sweep.iter())
+            .ok()
+            .map(|n| n.to_string())
+    }
+
+    fn part_2(sonar_sweep: &Self::Input) -> Option<String> {
+        count_filtered_increases(sonar_sweep, Day01::FILTER)
+            .ok()
+            .map(|n| n.to_string())
+    }
+}
+
+impl Day01 {
+    /// The three-measurement sliding window from the puzzle.
+    pub const FILTER: Filter = Filter::Sum(3);
+
+    /// Answers part 2 with the sweep smoothed by `filter` instead.
+    pub fn solve_filtered(lines: &[&str], filter: Filter) -> Result<String> {
+        let sonar_sweep = Self::parse(lines).map_err(|e| e.on_day(Self::DAY))?;
+
+        count_filtered_increases(&sonar_sweep, filter)
+            .map(|n| n.to_string())
+            .map_err(|e| e.on_day(Self::DAY))
+    }
+}
+
+/// How far apart two smoothed readings must be, relative to their size, to
+/// count as different rather than as rounding error.
+const TOLERANCE: f64 = 1e-9;
+
+pub fn count_filtered_increases(sonar_sweep: &[isize], filter: Filter) -> Result<usize> {
+    match filter {
+        // Windows of one width compare the same by mean as by sum, and summing
+        // integers is exact.
+        Filter::Sum(width) | Filter::Mean(width) => count_increases(
+            Windowed::new(sonar_sweep.iter().copied(), width).map(|w| w.iter().sum::<isize>()),
+        ),
+        Filter::Median(_) => {
+            count_increases(filter.apply(sonar_sweep.iter().map(|&depth| depth as f64)))
+        }
+        Filter::Exponential(_) => {
+            let smoothed = filter.apply(sonar_sweep.iter().map(|&depth| depth as f64));
+
+            Ok(Windowed::new(smoothed, 2)
+                .map(|w| w[1] - w[0] > TOLERANCE * w[0].abs().max(w[1].abs()).max(1.0))
+                .filter(|&increased| increased)
+                .count())
+        }
+    }
+}
+
+/// Counts the measurements larger than the one before, failing at the first
+/// one that cannot be compared, such as `NaN`.
+pub fn count_increases<T: PartialOrd + Clone>(
+    measurements: impl Iterator<Item = T>,
+) -> Result<usize> {
+    increases(OrderingIter::new(measurements))
+}
+
+fn increases<T, I>(mut orderings: OrderingIter<T, I>) -> Result<usize>
+where
+    I: Iterator<Item = T>,
+    T: PartialOrd + Clone,
+{
+    let increases = orderings
+        .by_ref()
+        .filter(|&d| d == Ordering::Greater)
+        .count();
+
+    match orderings.error() {
+        Some(err) => Err(err.clone()),
+        None => Ok(increases),
+    }
+}
+
+/// Parses a sweep of possibly fractional readings, where a blank line or
+/// `NaN` stands for a reading that is missing.
+pub fn parse_readings(lines: &[&str]) -> Result<Vec<f64>> {
+    parse_lines(lines, |line| {
+        if line.trim().is_empty() {
+            Ok(f64::NAN)
+        } else {
+            parse_number::<f64>(line)
+        }
+    })
+}
+
+/// Like `count_increases`, but deciding what to do with missing readings.
+pub fn count_increases_with(readings: &[f64], policy: Incomparable) -> Result<usize> {
+    increases(OrderingIter::with_policy(readings.iter(), policy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _01_part_1() {
+        let sonar_sweep: [isize; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let num_increases = OrderingIter::new(sonar_sweep.iter())
+            .filter(|&d| d == Ordering::Greater)
+            .count();
+
+        assert_eq!(num_increases, 7);
+    }
+
+    #[test]
+    fn _01_part_2() {
+        let sonar_sweep: [isize; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let num_increases =
+            count_filtered_increases(&sonar_sweep, Day01::FILTER).expect("incomparable");
+
+        assert_eq!(num_increases, 5);
+    }
+
+    #[test]
+    fn filters() {
+        let sonar_sweep: [isize; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+        assert_eq!(
+            count_filtered_increases(&sonar_sweep, Filter::Mean(3)),
+            Ok(5)
+        );
+        assert_eq!(
+            count_filtered_increases(&sonar_sweep, Filter::Median(3)),
+            Ok(4)
+        );
+        assert_eq!(
+            count_filtered_increases(&sonar_sweep, Filter::Sum(1)),
+            Ok(7)
+        );
+        assert_eq!(
+            count_filtered_increases(&sonar_sweep, Filter::Exponential(1.0)),
+            Ok(7)
+        );
+        assert_eq!(
+            Day01::solve_filtered(&["1", "3", "2", "4"], Filter::Sum(2)),
+            Ok("2".to_string())
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let steady = [3; 6];
+        let huge = [1 << 53, (1 << 53) + 1];
+
+        assert_eq!(
+            count_filtered_increases(&steady, Filter::Exponential(0.2)),
+            Ok(0)
+        );
+        assert_eq!(count_filtered_increases(&huge, Filter::Mean(1)), Ok(1));
+    }
+
+    #[test]
+    fn readings() {
+        let readings = parse_readings(&["1.5", "", "2", "NaN", "1.25"]).expect("no readings");
+
+        assert_eq!(readings.iter().filter(|r| r.is_nan()).count(), 2);
+        assert_eq!(count_increases_with(&readings, Incomparable::Skip), Ok(1));
+        assert_eq!(
+            count_increases(readings.iter()).map_err(|e| e.line()),
+            Err(Some(2))
+        );
+        assert_eq!(count_increases_with(&readings, Incomparable::Gap), Ok(0));
+        assert_eq!(
+            count_increases_with(&readings, Incomparable::Error).map_err(|e| e.line()),
+            Err(Some(2))
+        );
+        assert_eq!(
+            parse_readings(&["1", "x"]).map_err(|e| e.line()),
+            Err(Some(2))
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::Result;
+use crate::solver::Solver;
+use crate::submarine::*;
+use crate::submarine_command::*;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: usize = 2;
+
+    type Input = Vec<Command>;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        parse_script(lines)
+    }
+
+    fn part_1(planned_course: &Self::Input) -> Option<String> {
+        Some(
+            planned_course
+                .iter()
+                .fold(SubmarineV1::default(), execute)
+                .product()
+                .to_string(),
+        )
+    }
+
+    fn part_2(planned_course: &Self::Input) -> Option<String> {
+        Some(
+            planned_course
+                .iter()
+                .fold(SubmarineV2::default(), execute)
+                .product()
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn planned_course() -> Vec<Command> {
+        let planned_course = [
+            "forward 5",
+            "down 5",
+            "forward 8",
+            "up 3",
+            "down 8",
+            "forward 2",
+        ];
+
+        planned_course
+            .iter()
+            .map(|s| s.parse::<Command>().expect("unrecognized command"))
+            .collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn _01_position() {
+        let final_position = planned_course()
+            .into_iter()
+            .fold(SubmarineV1::default(), |curr, command| {
+                execute(curr, &command)
+            });
+        assert_eq!(final_position.product(), 150);
+    }
+
+    #[test]
+    fn _02_position() {
+        let final_position = planned_course()
+            .into_iter()
+            .fold(SubmarineV2::default(), |curr, command| {
+                execute(curr, &command)
+            });
+        assert_eq!(final_position.product(), 900);
+    }
+}
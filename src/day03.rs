@@ -0,0 +1,441 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter};
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::solver::Solver;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: usize = 3;
+
+    type Input = DiagnosticReport;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        DiagnosticReport::parse(lines)
+    }
+
+    fn part_1(diagnostic_report: &Self::Input) -> Option<String> {
+        diagnostic_report.power_consumption().map(|x| x.to_string())
+    }
+
+    fn part_2(diagnostic_report: &Self::Input) -> Option<String> {
+        diagnostic_report
+            .life_support_rating()
+            .map(|x| x.to_string())
+    }
+}
+
+/// A diagnostic report with every row packed into a word, most significant
+/// bit first, and the number of ones in each column counted up front.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiagnosticReport {
+    rows: Vec<u64>,
+    width: usize,
+    ones: Vec<usize>,
+}
+
+impl DiagnosticReport {
+    pub fn parse(lines: &[&str]) -> Result<Self> {
+        Self::parse_with(lines, Alphabet::BINARY)
+    }
+
+    /// Parses a report written with the given characters for zero and one.
+    pub fn parse_with(lines: &[&str], alphabet: Alphabet) -> Result<Self> {
+        let width = lines.first().map(|line| line.chars().count()).unwrap_or(0);
+        let mut rows = Vec::with_capacity(lines.len());
+        let mut ones = vec![0; width];
+
+        if width > 64 {
+            return Err(Error::new(ErrorKind::Malformed("at most 64 bits"), lines[0]).at_line(1));
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            let mut row = 0;
+
+            for (column, ch) in line.chars().enumerate() {
+                let bit = alphabet.bit(ch).ok_or_else(|| {
+                    Error::new(ErrorKind::UnexpectedCharacter(ch), line)
+                        .at_column(column + 1)
+                        .at_line(i + 1)
+                })? as usize;
+
+                if column < width {
+                    ones[column] += bit;
+                }
+                row = row << 1 | bit as u64;
+            }
+
+            if line.chars().count() != width {
+                return Err(
+                    Error::new(ErrorKind::Malformed("rows of equal width"), line).at_line(i + 1),
+                );
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Self { rows, width, ones })
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// The number of bits in each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn row(&self, i: usize) -> u64 {
+        self.rows[i]
+    }
+
+    /// Whether the bit in the given column, counting from the left, is set.
+    pub fn bit(&self, i: usize, column: usize) -> bool {
+        self.rows[i] >> (self.width - 1 - column) & 1 == 1
+    }
+
+    pub fn ones(&self, column: usize) -> usize {
+        self.ones[column]
+    }
+
+    pub fn zeros(&self, column: usize) -> usize {
+        self.len() - self.ones[column]
+    }
+
+    pub fn power_consumption(&self) -> Option<u64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(self.gamma_rate() * self.epsilon_rate())
+    }
+
+    pub fn gamma_rate(&self) -> u64 {
+        self.rate(&BitCriteria::most_common())
+    }
+
+    pub fn epsilon_rate(&self) -> u64 {
+        self.rate(&BitCriteria::least_common())
+    }
+
+    /// The bits picked by `bit_criteria` from the counts of every column.
+    pub fn rate(&self, bit_criteria: &BitCriteria) -> u64 {
+        (0..self.width).fold(0, |rate, column| {
+            rate << 1 | bit_criteria.pick(self.ones(column), self.zeros(column)) as u64
+        })
+    }
+
+    pub fn life_support_rating(&self) -> Option<u64> {
+        Some(self.oxygen_generator_rating()? * self.co2_scrubber_rating()?)
+    }
+
+    pub fn oxygen_generator_rating(&self) -> Option<u64> {
+        self.last_retained_match(&BitCriteria::most_common())
+    }
+
+    pub fn co2_scrubber_rating(&self) -> Option<u64> {
+        self.last_retained_match(&BitCriteria::least_common())
+    }
+
+    pub fn last_retained_match(&self, bit_criteria: &BitCriteria) -> Option<u64> {
+        self.elimination(bit_criteria).rating
+    }
+
+    /// Keeps the rows whose bit in each column in turn matches the one picked
+    /// by `bit_criteria` from the number of ones and zeros left in that
+    /// column, until only one is left, recording every round.
+    pub fn elimination(&self, bit_criteria: &BitCriteria) -> Elimination {
+        let mut retained = (0..self.len()).collect::<Vec<_>>();
+        let mut rounds = vec![];
+
+        for column in 0..self.width {
+            if retained.len() <= 1 {
+                break;
+            }
+
+            let ones = retained.iter().filter(|&&i| self.bit(i, column)).count();
+            let zeros = retained.len() - ones;
+            let kept = bit_criteria.pick(ones, zeros);
+
+            retained.retain(|&i| self.bit(i, column) == kept);
+            rounds.push(Round {
+                column,
+                ones,
+                zeros,
+                kept,
+                retained: retained.len(),
+            });
+        }
+
+        Elimination {
+            rounds,
+            rating: retained.first().map(|&i| self.rows[i]),
+        }
+    }
+}
+
+/// The characters a report uses for zero and one.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Alphabet {
+    pub zero: char,
+    pub one: char,
+}
+
+impl Alphabet {
+    pub const BINARY: Alphabet = Alphabet {
+        zero: '0',
+        one: '1',
+    };
+
+    pub fn new(zero: char, one: char) -> Self {
+        Self { zero, one }
+    }
+
+    pub fn bit(&self, ch: char) -> Option<bool> {
+        match ch {
+            ch if ch == self.zero => Some(false),
+            ch if ch == self.one => Some(true),
+            _ => None,
+        }
+    }
+}
+
+/// Picks the bit to keep from the number of ones and zeros in a column. A
+/// column where every row has the same bit always picks that bit, whatever
+/// the criteria, so no rating or rate can use a bit that no row has.
+pub struct BitCriteria {
+    predicate: Box<dyn Fn(usize, usize) -> Option<bool>>,
+    tie: bool,
+}
+
+impl BitCriteria {
+    /// Picks the bit returned by `predicate`, or the tie breaker if it
+    /// returns `None`.
+    pub fn new(predicate: impl Fn(usize, usize) -> Option<bool> + 'static) -> Self {
+        Self {
+            predicate: Box::new(predicate),
+            tie: true,
+        }
+    }
+
+    /// The more common bit, or one if they are equally common.
+    pub fn most_common() -> Self {
+        Self::new(|ones, zeros| match ones.cmp(&zeros) {
+            Ordering::Equal => None,
+            ordering => Some(ordering == Ordering::Greater),
+        })
+    }
+
+    /// The less common bit, or zero if they are equally common.
+    pub fn least_common() -> Self {
+        Self::new(|ones, zeros| match ones.cmp(&zeros) {
+            Ordering::Equal => None,
+            ordering => Some(ordering == Ordering::Less),
+        })
+        .on_tie(false)
+    }
+
+    /// Breaks ties in favour of `bit`.
+    pub fn on_tie(mut self, bit: bool) -> Self {
+        self.tie = bit;
+        self
+    }
+
+    pub fn pick(&self, ones: usize, zeros: usize) -> bool {
+        match (ones, zeros) {
+            (1.., 0) => true,
+            (0, 1..) => false,
+            _ => (self.predicate)(ones, zeros).unwrap_or(self.tie),
+        }
+    }
+}
+
+impl Debug for BitCriteria {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BitCriteria")
+            .field("tie", &self.tie)
+            .finish_non_exhaustive()
+    }
+}
+
+/// One column's worth of filtering: the counts among the rows still
+/// retained before it, the bit that was kept and how many rows have it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Round {
+    pub column: usize,
+    pub ones: usize,
+    pub zeros: usize,
+    pub kept: bool,
+    pub retained: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Elimination {
+    pub rounds: Vec<Round>,
+    pub rating: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIAGNOSTIC_REPORT: [&str; 12] = [
+        "00100", "11110", "10110", "10111", "10101", "01111", "00111", "11100", "10000", "11001",
+        "00010", "01010",
+    ];
+
+    fn diagnostic_report() -> DiagnosticReport {
+        DiagnosticReport::parse(&DIAGNOSTIC_REPORT).expect("bad report")
+    }
+
+    #[test]
+    fn _01_most_common() {
+        let report = diagnostic_report();
+        let most_common = BitCriteria::most_common();
+        let bits = (0..5)
+            .map(|i| most_common.pick(report.ones(i), report.zeros(i)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(bits, vec![true, false, true, true, false]);
+    }
+
+    #[test]
+    fn _01_least_common() {
+        let report = diagnostic_report();
+        let least_common = BitCriteria::least_common();
+        let bits = (0..5)
+            .map(|i| least_common.pick(report.ones(i), report.zeros(i)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(bits, vec![false, true, false, false, true]);
+    }
+
+    #[test]
+    fn _01_power_consumption() {
+        let report = diagnostic_report();
+
+        assert_eq!((report.gamma_rate(), report.epsilon_rate()), (22, 9));
+        assert_eq!(report.power_consumption(), Some(198));
+    }
+
+    #[test]
+    fn _02_oxygen_generator_rating() {
+        assert_eq!(diagnostic_report().oxygen_generator_rating(), Some(23));
+    }
+
+    #[test]
+    fn _02_co2_scrubber_rating() {
+        assert_eq!(diagnostic_report().co2_scrubber_rating(), Some(10));
+    }
+
+    #[test]
+    fn _02_life_support_rating() {
+        assert_eq!(diagnostic_report().life_support_rating(), Some(230));
+    }
+
+    #[test]
+    fn packed() {
+        let report = diagnostic_report();
+
+        assert_eq!((report.len(), report.width()), (12, 5));
+        assert_eq!(report.row(1), 0b11110);
+        assert!(report.bit(1, 0) && !report.bit(1, 4));
+
+        let wide = "1".repeat(64);
+        let report = DiagnosticReport::parse(&[&wide, &wide]).expect("bad report");
+        assert_eq!(report.oxygen_generator_rating(), Some(u64::MAX));
+
+        let empty = DiagnosticReport::parse(&[]).expect("bad report");
+        assert_eq!(empty.power_consumption(), None);
+        assert_eq!(empty.life_support_rating(), None);
+    }
+
+    #[test]
+    fn _02_elimination() {
+        let elimination = diagnostic_report().elimination(&BitCriteria::least_common());
+
+        assert_eq!(elimination.rating, Some(10));
+        assert_eq!(
+            elimination
+                .rounds
+                .iter()
+                .map(|round| (round.kept, round.retained))
+                .collect::<Vec<_>>(),
+            vec![(false, 5), (true, 2), (false, 1)]
+        );
+        assert_eq!(
+            elimination.rounds[1],
+            Round {
+                column: 1,
+                ones: 2,
+                zeros: 3,
+                kept: true,
+                retained: 2
+            }
+        );
+    }
+
+    #[test]
+    fn _02_same_bits() {
+        let report = DiagnosticReport::parse(&["10", "11"]).expect("bad report");
+        let elimination = report.elimination(&BitCriteria::least_common());
+
+        assert_eq!(report.co2_scrubber_rating(), Some(0b10));
+        assert_eq!(report.life_support_rating(), Some(6));
+        assert_eq!((report.gamma_rate(), report.epsilon_rate()), (0b11, 0b10));
+        assert_eq!(report.power_consumption(), Some(6));
+        assert_eq!(
+            elimination
+                .rounds
+                .iter()
+                .map(|round| (round.kept, round.retained))
+                .collect::<Vec<_>>(),
+            vec![(true, 2), (false, 1)]
+        );
+    }
+
+    #[test]
+    fn criteria() {
+        let report = diagnostic_report();
+
+        assert!(!BitCriteria::most_common().on_tie(false).pick(3, 3));
+        assert!(BitCriteria::new(|_, _| Some(false)).pick(2, 0));
+        assert!(!BitCriteria::least_common().on_tie(true).pick(0, 2));
+        assert_eq!(
+            report.last_retained_match(&BitCriteria::new(|_, _| Some(true))),
+            Some(0b11110)
+        );
+        assert_eq!(report.rate(&BitCriteria::new(|ones, _| Some(ones > 9))), 0);
+    }
+
+    #[test]
+    fn alphabet() {
+        let report = DiagnosticReport::parse_with(&["#..#", ".##."], Alphabet::new('.', '#'))
+            .expect("bad report");
+
+        assert_eq!((report.row(0), report.row(1)), (0b1001, 0b0110));
+
+        let err = DiagnosticReport::parse_with(&["#0"], Alphabet::new('.', '#')).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnexpectedCharacter('0'));
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = DiagnosticReport::parse(&["00100", "11210"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnexpectedCharacter('2'));
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+
+        let err = DiagnosticReport::parse(&["00100", "1111"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("rows of equal width"));
+        assert_eq!(err.line(), Some(2));
+
+        let err = DiagnosticReport::parse(&[&"0".repeat(65)]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("at most 64 bits"));
+    }
+}
@@ -0,0 +1,607 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{parse_number, Error, ErrorKind, Result};
+use crate::grid::Grid;
+use crate::solver::Solver;
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: usize = 4;
+
+    type Input = (Vec<usize>, Vec<Board>);
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        parse_bingo(lines)
+    }
+
+    fn part_1((draw, boards): &Self::Input) -> Option<String> {
+        play_bingo(draw, &mut boards.clone()).map(|x| x.to_string())
+    }
+
+    fn part_2((draw, boards): &Self::Input) -> Option<String> {
+        lose_bingo(draw, &mut boards.clone()).map(|x| x.to_string())
+    }
+}
+
+pub fn play_bingo(draw: &[usize], boards: &mut [Board]) -> Option<usize> {
+    simulate(draw, boards)
+        .first()
+        .map(|win| win.score * win.number)
+}
+
+pub fn lose_bingo(draw: &[usize], boards: &mut [Board]) -> Option<usize> {
+    let wins = simulate(draw, boards);
+    let last_turn = wins.last()?.turn;
+
+    wins.iter()
+        .find(|win| win.turn == last_turn)
+        .map(|win| win.score * win.number)
+}
+
+/// A board winning on the `turn`th number drawn (counting from zero).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Win {
+    pub board: usize,
+    pub turn: usize,
+    pub number: usize,
+    pub score: usize,
+}
+
+/// Plays every number in `draw` on the boards that have not won yet,
+/// returning the boards in the order they won. Boards that win on the same
+/// number are in the order they were given in.
+pub fn simulate(draw: &[usize], boards: &mut [Board]) -> Vec<Win> {
+    let mut wins = vec![];
+
+    for (turn, &number) in draw.iter().enumerate() {
+        for (i, board) in boards.iter_mut().enumerate() {
+            if !board.has_won() && board.play(number) {
+                wins.push(Win {
+                    board: i,
+                    turn,
+                    number,
+                    score: board.score(),
+                });
+            }
+        }
+
+        if wins.len() == boards.len() {
+            break;
+        }
+    }
+
+    wins
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Number {
+    Unmarked(usize),
+    Marked(usize),
+}
+
+impl Number {
+    fn value(&self) -> usize {
+        match *self {
+            Number::Unmarked(n) => n,
+            Number::Marked(n) => n,
+        }
+    }
+}
+
+/// The lines of cells that win a game once they are all marked.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Pattern {
+    Rows,
+    Columns,
+    /// Both diagonals of a square board.
+    Diagonals,
+    Corners,
+    Full,
+}
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    numbers: Grid<Number>,
+    cells: HashMap<usize, Vec<(usize, usize)>>,
+    patterns: Vec<Pattern>,
+    marked_in_rows: Vec<usize>,
+    marked_in_columns: Vec<usize>,
+    marked_in_diagonals: [usize; 2],
+    marked_corners: usize,
+    marked: usize,
+    score: usize,
+    won: bool,
+}
+
+impl Board {
+    /// A board that wins on a full row or column.
+    pub fn new(numbers: Grid<usize>) -> Self {
+        let mut cells = HashMap::<_, Vec<_>>::new();
+
+        for (coord, &n) in numbers.indexed_iter() {
+            cells.entry(n).or_default().push(coord);
+        }
+
+        Self {
+            marked_in_rows: vec![0; numbers.height()],
+            marked_in_columns: vec![0; numbers.width()],
+            marked_in_diagonals: [0; 2],
+            marked_corners: 0,
+            marked: 0,
+            score: numbers.iter().sum(),
+            won: false,
+            numbers: numbers.map(|&n| Number::Unmarked(n)),
+            cells,
+            patterns: vec![Pattern::Rows, Pattern::Columns],
+        }
+    }
+
+    pub fn with_patterns(mut self, patterns: &[Pattern]) -> Self {
+        self.patterns = patterns.to_vec();
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.numbers.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.numbers.height()
+    }
+
+    /// The numbers on the board, row by row.
+    pub fn numbers(&self) -> impl Iterator<Item = usize> + '_ {
+        self.numbers.iter().map(Number::value)
+    }
+
+    pub fn is_marked(&self, x: usize, y: usize) -> bool {
+        matches!(self.numbers.get(x, y), Some(Number::Marked(_)))
+    }
+
+    /// Marks `number` wherever it is on the board, returning whether the
+    /// board has won.
+    pub fn play(&mut self, number: usize) -> bool {
+        for (x, y) in self.cells.remove(&number).unwrap_or_default() {
+            self.mark(x, y);
+        }
+
+        self.won
+    }
+
+    fn mark(&mut self, x: usize, y: usize) {
+        let (width, height) = (self.width(), self.height());
+        let square = width == height;
+        let is_corner = (x == 0 || x == width - 1) && (y == 0 || y == height - 1);
+
+        let n = self.numbers[(x, y)].value();
+
+        self.numbers[(x, y)] = Number::Marked(n);
+        self.score -= n;
+        self.marked += 1;
+        self.marked_in_rows[y] += 1;
+        self.marked_in_columns[x] += 1;
+        if square && x == y {
+            self.marked_in_diagonals[0] += 1;
+        }
+        if square && x + y == width - 1 {
+            self.marked_in_diagonals[1] += 1;
+        }
+        if is_corner {
+            self.marked_corners += 1;
+        }
+
+        let corners = match (width, height) {
+            (1, 1) => 1,
+            (1, _) | (_, 1) => 2,
+            _ => 4,
+        };
+
+        self.won |= self.patterns.iter().any(|pattern| match pattern {
+            Pattern::Rows => self.marked_in_rows[y] == width,
+            Pattern::Columns => self.marked_in_columns[x] == height,
+            Pattern::Diagonals => square && self.marked_in_diagonals.contains(&width),
+            Pattern::Corners => self.marked_corners == corners,
+            Pattern::Full => self.marked == self.numbers.len(),
+        });
+    }
+
+    /// The numbers in each line of cells that wins on its own, for every
+    /// pattern the board plays.
+    pub fn lines(&self) -> Vec<Vec<usize>> {
+        let (width, height) = (self.width(), self.height());
+        let line = |coords: &mut dyn Iterator<Item = (usize, usize)>| {
+            let mut numbers = coords
+                .map(|(x, y)| self.numbers[(x, y)].value())
+                .collect::<Vec<_>>();
+
+            numbers.sort_unstable();
+            numbers.dedup();
+            numbers
+        };
+        let mut lines = vec![];
+
+        for pattern in &self.patterns {
+            match pattern {
+                Pattern::Rows => {
+                    lines.extend((0..height).map(|y| line(&mut (0..width).map(|x| (x, y)))))
+                }
+                Pattern::Columns => {
+                    lines.extend((0..width).map(|x| line(&mut (0..height).map(|y| (x, y)))))
+                }
+                Pattern::Diagonals if width == height => {
+                    lines.push(line(&mut (0..width).map(|i| (i, i))));
+                    lines.push(line(&mut (0..width).map(|i| (width - 1 - i, i))));
+                }
+                Pattern::Corners if width > 0 && height > 0 => {
+                    let (right, bottom) = (width - 1, height - 1);
+                    let corners = [(0, 0), (right, 0), (0, bottom), (right, bottom)];
+
+                    lines.push(line(&mut corners.iter().copied()));
+                }
+                Pattern::Full => lines.push(line(&mut self.numbers.coords())),
+                Pattern::Diagonals | Pattern::Corners => {}
+            }
+        }
+
+        lines
+    }
+
+    /// The sum of the unmarked numbers.
+    pub fn score(&self) -> usize {
+        self.score
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.won
+    }
+}
+
+pub fn parse_bingo_draw(line: &str) -> Result<Vec<usize>> {
+    line.trim()
+        .split(',')
+        .map(|n| parse_number::<usize>(n).map_err(|e| e.within(line, n)))
+        .collect()
+}
+
+/// Parses the next board from `lines`, which yields each line together with
+/// its line number, skipping blank lines before it and stopping at the first
+/// blank line after it. Returns `None` if there are no boards left.
+///
+/// The whole board is read even if it is malformed, so that parsing can
+/// carry on with the next one.
+pub fn parse_bingo_board<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<Option<Board>> {
+    let block = lines
+        .skip_while(|(_, line)| line.trim().is_empty())
+        .take_while(|(_, line)| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+
+    if block.is_empty() {
+        return Ok(None);
+    }
+
+    let mut rows = Vec::with_capacity(block.len());
+    let mut seen = HashSet::new();
+
+    for &(line_number, line) in &block {
+        let mut row = vec![];
+
+        for n in line.split_whitespace() {
+            let number =
+                parse_number::<usize>(n).map_err(|e| e.within(line, n).at_line(line_number))?;
+
+            if !seen.insert(number) {
+                return Err(
+                    Error::new(ErrorKind::Malformed("each number once per board"), n)
+                        .within(line, n)
+                        .at_line(line_number),
+                );
+            }
+
+            row.push(number);
+        }
+
+        rows.push(row);
+    }
+
+    let numbers = Grid::from_rows(rows).map_err(|e| {
+        let (line_number, line) = block[e.line().unwrap_or(1) - 1];
+
+        Error::new(e.kind().clone(), line).at_line(line_number)
+    })?;
+
+    Ok(Some(Board::new(numbers)))
+}
+
+/// How `parse_bingo_with` deals with a malformed board.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Strictness {
+    /// Fail on the first problem.
+    Strict,
+    /// Leave the board out, keeping the problem in `Bingo::problems`.
+    Lenient,
+}
+
+#[derive(Clone, Debug)]
+pub struct Bingo {
+    pub draw: Vec<usize>,
+    pub boards: Vec<Board>,
+    /// What was wrong with each of the boards that were left out.
+    pub problems: Vec<Error>,
+}
+
+pub fn parse_bingo(lines: &[&str]) -> Result<(Vec<usize>, Vec<Board>)> {
+    let bingo = parse_bingo_with(lines, Strictness::Strict)?;
+
+    Ok((bingo.draw, bingo.boards))
+}
+
+/// Parses the draw and the boards, checking that every board is the same
+/// size as the first, has no number on it twice and only has numbers that
+/// are drawn.
+pub fn parse_bingo_with(lines: &[&str], strictness: Strictness) -> Result<Bingo> {
+    let mut lines = lines.iter().enumerate().map(|(i, line)| (i + 1, *line));
+    let (line_number, line) = lines
+        .find(|(_, line)| !line.trim().is_empty())
+        .ok_or_else(|| Error::new(ErrorKind::Empty, ""))?;
+    let draw = parse_bingo_draw(line).map_err(|e| e.at_line(line_number))?;
+    let drawn = draw.iter().copied().collect::<HashSet<_>>();
+    let mut bingo = Bingo {
+        draw,
+        boards: vec![],
+        problems: vec![],
+    };
+
+    loop {
+        let mut block = lines
+            .by_ref()
+            .skip_while(|(_, line)| line.trim().is_empty())
+            .peekable();
+        let first_line = match block.peek() {
+            Some(&first_line) => first_line,
+            None => break,
+        };
+        let board = parse_bingo_board(&mut block).and_then(|board| {
+            let board = board.ok_or_else(|| {
+                Error::new(ErrorKind::Malformed("a board"), first_line.1).at_line(first_line.0)
+            })?;
+            let size = (board.width(), board.height());
+
+            if bingo
+                .boards
+                .first()
+                .map(|b| (b.width(), b.height()))
+                .unwrap_or(size)
+                != size
+            {
+                return Err(
+                    Error::new(ErrorKind::Malformed("boards of equal size"), first_line.1)
+                        .at_line(first_line.0),
+                );
+            }
+
+            let undrawn_at = board.numbers().position(|n| !drawn.contains(&n));
+
+            match undrawn_at {
+                Some(i) => Err(undrawn(&board, i, first_line.0)),
+                None => Ok(board),
+            }
+        });
+
+        match (board, strictness) {
+            (Ok(board), _) => bingo.boards.push(board),
+            (Err(err), Strictness::Strict) => return Err(err),
+            (Err(err), Strictness::Lenient) => bingo.problems.push(err),
+        }
+    }
+
+    Ok(bingo)
+}
+
+/// Points at the `i`th number on a board, which is not in the draw.
+fn undrawn(board: &Board, i: usize, first_line: usize) -> Error {
+    let number = board.numbers().nth(i).unwrap_or(0);
+
+    Error::new(
+        ErrorKind::Malformed("a number from the draw"),
+        &number.to_string(),
+    )
+    .at_line(first_line + i / board.width())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+"#;
+
+    #[test]
+    fn _01_example() {
+        let lines = EXAMPLE.split('\n').collect::<Vec<_>>();
+        let (draw, mut boards) = parse_bingo(&lines).expect("could not parse");
+
+        assert_eq!(
+            draw,
+            vec![
+                7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24, 10, 16, 13, 6, 15, 25, 12, 22, 18, 20, 8,
+                19, 3, 26, 1
+            ]
+        );
+        assert_eq!(boards.len(), 3);
+        assert_eq!(
+            boards[0].numbers().collect::<Vec<_>>(),
+            vec![
+                22, 13, 17, 11, 0, 8, 2, 23, 4, 24, 21, 9, 14, 16, 7, 6, 10, 3, 18, 5, 1, 12, 20,
+                15, 19
+            ]
+        );
+        assert_eq!(
+            boards[1].numbers().collect::<Vec<_>>(),
+            vec![
+                3, 15, 0, 2, 22, 9, 18, 13, 17, 5, 19, 8, 7, 25, 23, 20, 11, 10, 24, 4, 14, 21, 16,
+                12, 6
+            ]
+        );
+        assert_eq!(
+            boards[2].numbers().collect::<Vec<_>>(),
+            vec![
+                14, 21, 17, 24, 4, 10, 16, 15, 9, 19, 18, 8, 23, 26, 20, 22, 11, 13, 6, 5, 2, 0,
+                12, 3, 7
+            ]
+        );
+        assert_eq!(play_bingo(&draw, &mut boards), Some(4512));
+    }
+
+    #[test]
+    fn _02_example() {
+        let lines = EXAMPLE.split('\n').collect::<Vec<_>>();
+        let (draw, mut boards) = parse_bingo(&lines).expect("could not parse");
+
+        assert_eq!(lose_bingo(&draw, &mut boards), Some(1924));
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let lines = EXAMPLE.split('\n').collect::<Vec<_>>();
+        let mut bad_draw = lines.clone();
+        bad_draw[1] = "7,4,x,5";
+
+        let err = parse_bingo(&bad_draw).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidNumber);
+        assert_eq!((err.line(), err.column()), (Some(2), Some(5)));
+
+        let mut bad_board = lines.clone();
+        bad_board[5] = "21  9 14 1b  7";
+
+        let err = parse_bingo(&bad_board).unwrap_err();
+        assert_eq!(err.text(), "1b");
+        assert_eq!((err.line(), err.column()), (Some(6), Some(10)));
+    }
+
+    #[test]
+    fn win_order() {
+        let lines = EXAMPLE.split('\n').collect::<Vec<_>>();
+        let (draw, mut boards) = parse_bingo(&lines).expect("could not parse");
+        let wins = simulate(&draw, &mut boards);
+
+        assert_eq!(
+            wins.iter()
+                .map(|win| (win.board, win.number))
+                .collect::<Vec<_>>(),
+            vec![(2, 24), (0, 16), (1, 13)]
+        );
+        assert_eq!((wins[2].turn, wins[2].score), (14, 148));
+    }
+
+    #[test]
+    fn patterns() {
+        let numbers = Grid::from_fn(3, 3, |x, y| 3 * y + x);
+        let play = |patterns: &[Pattern], draw: &[usize]| {
+            let mut board = Board::new(numbers.clone()).with_patterns(patterns);
+
+            draw.iter().position(|&n| board.play(n))
+        };
+
+        assert_eq!(play(&[Pattern::Diagonals], &[0, 1, 4, 2, 8]), Some(4));
+        assert_eq!(play(&[Pattern::Diagonals], &[2, 4, 6]), Some(2));
+        assert_eq!(play(&[Pattern::Corners], &[0, 2, 4, 6, 8]), Some(4));
+        assert_eq!(play(&[Pattern::Rows], &[0, 3, 6]), None);
+        assert_eq!(play(&[Pattern::Columns], &[0, 3, 6]), Some(2));
+        assert_eq!(play(&[Pattern::Full], &[0, 1, 2, 3, 4, 5, 6, 7]), None);
+
+        let board =
+            Board::new(numbers.clone()).with_patterns(&[Pattern::Diagonals, Pattern::Corners]);
+        assert_eq!(
+            board.lines(),
+            vec![vec![0, 4, 8], vec![2, 4, 6], vec![0, 2, 6, 8]]
+        );
+        assert_eq!(Board::new(numbers).lines().len(), 6);
+    }
+
+    #[test]
+    fn sizes() {
+        let lines = ["1,2,3,4", "", "1 2", "3 4", "", "4 3", "2 1"];
+        let (draw, mut boards) = parse_bingo(&lines).expect("could not parse");
+
+        assert_eq!(
+            boards
+                .iter()
+                .map(|b| (b.width(), b.height()))
+                .collect::<Vec<_>>(),
+            vec![(2, 2), (2, 2)]
+        );
+        assert_eq!(play_bingo(&draw, &mut boards), Some(14));
+
+        let err = parse_bingo(&["1", "", "1 2", "3"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("rows of equal width"));
+        assert_eq!((err.line(), err.text()), (Some(4), "3"));
+    }
+
+    #[test]
+    fn strict() {
+        let lines = [
+            "1,2,3,4", "", "1 2", "3 4", "", "1 2 3", "", "1 2", "1 4", "", "1 2", "3 5", "",
+            "2 1", "4 3",
+        ];
+
+        let err = parse_bingo(&lines).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("boards of equal size"));
+        assert_eq!(err.line(), Some(6));
+
+        let bingo = parse_bingo_with(&lines, Strictness::Lenient).expect("could not parse");
+        assert_eq!(bingo.boards.len(), 2);
+        assert_eq!(
+            bingo.boards[1].numbers().collect::<Vec<_>>(),
+            vec![2, 1, 4, 3]
+        );
+        assert_eq!(
+            bingo
+                .problems
+                .iter()
+                .map(|e| (e.kind().clone(), e.line(), e.text().to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ErrorKind::Malformed("boards of equal size"),
+                    Some(6),
+                    "1 2 3".to_string()
+                ),
+                (
+                    ErrorKind::Malformed("each number once per board"),
+                    Some(9),
+                    "1".to_string()
+                ),
+                (
+                    ErrorKind::Malformed("a number from the draw"),
+                    Some(12),
+                    "5".to_string()
+                ),
+            ]
+        );
+
+        let err = parse_bingo(&["1,x"]).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), Some(3)));
+    }
+}
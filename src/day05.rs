@@ -0,0 +1,1043 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::error::{parse_lines, parse_number, Error, ErrorKind, Result};
+use crate::solver::Solver;
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: usize = 5;
+
+    type Input = Vec<VentLine>;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        parse_lines(lines, str::parse::<VentLine>)
+    }
+
+    fn part_1(vent_lines: &Self::Input) -> Option<String> {
+        let straight = vent_lines
+            .iter()
+            .filter(|l| {
+                matches!(
+                    l.orientation(),
+                    Orientation::Horizontal | Orientation::Vertical
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Some(count_overlapping(&straight).to_string())
+    }
+
+    fn part_2(vent_lines: &Self::Input) -> Option<String> {
+        let all = vent_lines.iter().collect::<Vec<_>>();
+
+        Some(count_overlapping(&all).to_string())
+    }
+}
+
+/// The furthest from zero a parsed coordinate may be, leaving room to work
+/// out where lines meet exactly.
+pub const MAX_COORDINATE: isize = (1 << 62) - 1;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or_else(|| {
+            Error::new(ErrorKind::MissingToken(","), s.trim()).within(s, s.trim())
+        })?;
+        let coordinate = |n: &str| {
+            parse_number::<isize>(n)
+                .and_then(|c| {
+                    Some(c)
+                        .filter(|c| c.unsigned_abs() <= MAX_COORDINATE as usize)
+                        .ok_or_else(|| {
+                            Error::new(ErrorKind::Malformed("a smaller coordinate"), n.trim())
+                                .within(n, n.trim())
+                        })
+                })
+                .map_err(|e| e.within(s, n))
+        };
+
+        Ok(Self {
+            x: coordinate(x)?,
+            y: coordinate(y)?,
+        })
+    }
+}
+
+impl Point {
+    pub fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn min(&self, other: &Point) -> Self {
+        Point {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    pub fn max(&self, other: &Point) -> Self {
+        Point {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+}
+
+/// Which way a vent line runs. A line covering a single point counts as
+/// horizontal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Horizontal,
+    Vertical,
+    /// At exactly 45 degrees.
+    Diagonal,
+    General,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct VentLine {
+    source: Point,
+    dest: Point,
+}
+
+impl FromStr for VentLine {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (source, dest) = s
+            .split_once("->")
+            .ok_or_else(|| Error::new(ErrorKind::MissingToken("->"), s))?;
+        let source = source.parse::<Point>().map_err(|e| e.within(s, source))?;
+        let dest = dest.parse::<Point>().map_err(|e| e.within(s, dest))?;
+
+        Ok(Self { source, dest })
+    }
+}
+
+impl VentLine {
+    pub fn new(x0: isize, y0: isize, x1: isize, y1: isize) -> Self {
+        let source = Point::new(x0, y0);
+        let dest = Point::new(x1, y1);
+
+        Self { source, dest }
+    }
+
+    pub fn source(&self) -> &Point {
+        &self.source
+    }
+
+    pub fn dest(&self) -> &Point {
+        &self.dest
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        let (dx, dy) = self.delta();
+
+        if dy == 0 {
+            Orientation::Horizontal
+        } else if dx == 0 {
+            Orientation::Vertical
+        } else if dx.abs() == dy.abs() {
+            Orientation::Diagonal
+        } else {
+            Orientation::General
+        }
+    }
+
+    /// The way from the source to the destination, wide enough that
+    /// products of two of them cannot overflow.
+    fn delta(&self) -> (i128, i128) {
+        (
+            self.dest.x as i128 - self.source.x as i128,
+            self.dest.y as i128 - self.source.y as i128,
+        )
+    }
+
+    /// The smallest move from one covered point to the next, which for
+    /// other slopes skips the points the line only passes between.
+    fn step(&self) -> (i128, i128) {
+        let (dx, dy) = self.delta();
+
+        match self.num_steps() {
+            0 => (0, 0),
+            n => (dx / n, dy / n),
+        }
+    }
+
+    fn num_steps(&self) -> i128 {
+        let (dx, dy) = self.delta();
+
+        gcd(dx.abs(), dy.abs())
+    }
+
+    fn at(&self, offset: i128) -> Point {
+        let (dx, dy) = self.step();
+
+        // Every covered point lies between the ends, so it fits in an `isize`.
+        Point::new(
+            (self.source.x as i128 + offset * dx) as isize,
+            (self.source.y as i128 + offset * dy) as isize,
+        )
+    }
+
+    /// Every point the line covers, from the source to the destination.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..=self.num_steps()).map(move |offset| self.at(offset))
+    }
+
+    /// How many steps from the source the line covers `point`, if it does.
+    fn offset(&self, point: &Point) -> Option<i128> {
+        let (dx, dy) = self.step();
+        let (wx, wy) = (
+            point.x as i128 - self.source.x as i128,
+            point.y as i128 - self.source.y as i128,
+        );
+        let length = dx * dx + dy * dy;
+
+        if length == 0 {
+            return Some(0).filter(|_| (wx, wy) == (0, 0));
+        }
+
+        let along = wx * dx + wy * dy;
+        Some(along / length).filter(|&offset| {
+            wx * dy == wy * dx && along % length == 0 && (0..=self.num_steps()).contains(&offset)
+        })
+    }
+
+    /// The part of the line covered by `other` too, as a line of its own
+    /// that may be a single point, worked out from their equations rather
+    /// than by walking either of them.
+    pub fn intersection(&self, other: &VentLine) -> Option<VentLine> {
+        let (ux, uy) = self.step();
+        let (vx, vy) = other.step();
+        let (wx, wy) = (
+            other.source.x as i128 - self.source.x as i128,
+            other.source.y as i128 - self.source.y as i128,
+        );
+        let point = |p: Point| VentLine { source: p, dest: p };
+
+        if (ux, uy) == (0, 0) || (vx, vy) == (0, 0) {
+            let (p, line) = if (ux, uy) == (0, 0) {
+                (self.source, other)
+            } else {
+                (other.source, self)
+            };
+
+            return line.offset(&p).map(|_| point(p));
+        }
+
+        let cross = ux * vy - uy * vx;
+        if cross != 0 {
+            let along = wx * vy - wy * vx;
+            let offset = along / cross;
+
+            if along % cross != 0 || !(0..=self.num_steps()).contains(&offset) {
+                return None;
+            }
+
+            let p = self.at(offset);
+            return other.offset(&p).map(|_| point(p));
+        }
+
+        // Parallel lines only meet if they lie on the same lattice line, in
+        // which case they share every point between their overlapping ends.
+        let length = ux * ux + uy * uy;
+        let along = wx * ux + wy * uy;
+        if wx * uy != wy * ux || along % length != 0 {
+            return None;
+        }
+
+        let start = along / length;
+        let end = start + (ux * vx + uy * vy).signum() * other.num_steps();
+        let from = start.min(end).max(0);
+        let to = start.max(end).min(self.num_steps());
+
+        Some(VentLine {
+            source: self.at(from),
+            dest: self.at(to),
+        })
+        .filter(|_| from <= to)
+    }
+
+    /// The step between covered points pointing right, or down if the line
+    /// is vertical, so that lines on the same lattice line agree on it.
+    fn direction(&self) -> (i128, i128) {
+        match self.step() {
+            (a, b) if a < 0 || (a == 0 && b < 0) => (-a, -b),
+            step => step,
+        }
+    }
+}
+
+/// Where `point` is on the lattice line through it along `direction`: a
+/// value shared by every point on that line, and how many steps along the
+/// line the point is from some fixed point on it.
+fn place(point: &Point, (a, b): (i128, i128)) -> (i128, i128) {
+    let (x, y) = (point.x as i128, point.y as i128);
+
+    (b * x - a * y, if a > 0 { x.div_euclid(a) } else { y })
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The number of points covered by at least two of the lines, found by
+/// intersecting every pair of lines instead of drawing them. Overlaps along
+/// the same lattice line are merged as ranges, so however long they are
+/// they take no more room than their ends.
+pub fn count_overlapping(lines: &[&VentLine]) -> usize {
+    let mut spans = HashMap::<(i128, i128), HashMap<i128, Vec<(i128, i128)>>>::new();
+    let mut overlaps = vec![];
+    let mut crossings = HashSet::new();
+
+    for (i, a) in lines.iter().enumerate() {
+        for b in &lines[i + 1..] {
+            match a.intersection(b) {
+                Some(overlap) if overlap.num_steps() == 0 => {
+                    crossings.insert(overlap.source);
+                }
+                Some(overlap) => {
+                    let direction = overlap.direction();
+                    let (line, from) = place(&overlap.source, direction);
+                    let (_, to) = place(&overlap.dest, direction);
+
+                    spans
+                        .entry(direction)
+                        .or_default()
+                        .entry(line)
+                        .or_default()
+                        .push((from.min(to), from.max(to)));
+                    overlaps.push(overlap);
+                }
+                None => {}
+            }
+        }
+    }
+
+    let mut count = 0;
+    for ranges in spans.values_mut().flat_map(HashMap::values_mut) {
+        *ranges = merge(ranges);
+        count += ranges.iter().map(|(from, to)| to - from + 1).sum::<i128>();
+    }
+
+    // A point has at most one lattice line through it per direction, and
+    // the merged spans on that line are disjoint.
+    let num_spans = |point: &Point| {
+        spans
+            .iter()
+            .filter(|(&direction, lines)| {
+                let (line, at) = place(point, direction);
+                let ranges = match lines.get(&line) {
+                    Some(ranges) => ranges,
+                    None => return false,
+                };
+                let after = ranges.partition_point(|&(from, _)| from <= at);
+
+                after > 0 && ranges[after - 1].1 >= at
+            })
+            .count()
+    };
+
+    // Spans in different directions can still cross, and a point where they
+    // do was counted once for each of them.
+    let mut shared = HashSet::new();
+    for (i, a) in overlaps.iter().enumerate() {
+        for b in &overlaps[i + 1..] {
+            if a.direction() != b.direction() {
+                shared.extend(a.intersection(b).map(|point| point.source));
+            }
+        }
+    }
+
+    let isolated = crossings.iter().filter(|point| num_spans(point) == 0);
+    let repeated = shared.iter().map(|point| num_spans(point) - 1);
+
+    count as usize + isolated.count() - repeated.sum::<usize>()
+}
+
+/// Sorts the ranges and joins the ones that overlap or touch.
+fn merge(ranges: &[(i128, i128)]) -> Vec<(i128, i128)> {
+    let mut sorted = ranges.to_vec();
+    let mut merged = Vec::<(i128, i128)>::with_capacity(sorted.len());
+
+    sorted.sort_unstable();
+    for (from, to) in sorted {
+        match merged.last_mut() {
+            Some(last) if from <= last.1 + 1 => last.1 = last.1.max(to),
+            _ => merged.push((from, to)),
+        }
+    }
+
+    merged
+}
+
+/// The most points a `VentDiagram` holds counts for, and the largest area it
+/// draws a picture of, so long or far apart lines cannot make either too big
+/// to hold.
+pub const MAX_PIXELS: usize = 1 << 22;
+
+/// The side of the square chunks a `VentDiagram` allocates at a time.
+const CHUNK: isize = 64;
+
+/// How many lines cover each point, stored in chunks that are allocated as
+/// lines reach them, so any coordinates can be drawn on.
+#[derive(Default)]
+pub struct VentDiagram {
+    chunks: HashMap<(isize, isize), Vec<usize>>,
+    bounds: Option<(Point, Point)>,
+}
+
+impl VentDiagram {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes room for the chunks covering `0..=size` in both directions,
+    /// without allocating any of them yet, up to as many as `push` allows.
+    pub fn with_capacity(size: usize) -> Self {
+        let most = MAX_PIXELS / (CHUNK * CHUNK) as usize;
+        let side = size / CHUNK as usize + 1;
+        let chunks = side.checked_mul(side).map_or(most, |n| n.min(most));
+
+        Self {
+            chunks: HashMap::with_capacity(chunks),
+            bounds: None,
+        }
+    }
+
+    fn locate(point: &Point) -> ((isize, isize), usize) {
+        let chunk = (point.x.div_euclid(CHUNK), point.y.div_euclid(CHUNK));
+        let index = point.y.rem_euclid(CHUNK) * CHUNK + point.x.rem_euclid(CHUNK);
+
+        (chunk, index as usize)
+    }
+
+    /// Draws the line on the diagram, unless that would leave it holding
+    /// counts for more than `MAX_PIXELS` points, in which case it is left as
+    /// it was.
+    pub fn push(&mut self, line: &VentLine) -> Result<()> {
+        let cells = (CHUNK * CHUNK) as usize;
+        let too_many = || {
+            let text = format!(
+                "{},{} -> {},{}",
+                line.source.x, line.source.y, line.dest.x, line.dest.y
+            );
+            Error::new(ErrorKind::LimitExceeded("pixels", MAX_PIXELS), &text)
+        };
+
+        // Checking the length first keeps the walk over the line's chunks
+        // short.
+        if line.num_steps() >= MAX_PIXELS as i128 {
+            return Err(too_many());
+        }
+
+        let new_chunks = line
+            .points()
+            .map(|point| Self::locate(&point).0)
+            .filter(|chunk| !self.chunks.contains_key(chunk))
+            .collect::<HashSet<_>>();
+        if (self.chunks.len() + new_chunks.len()) * cells > MAX_PIXELS {
+            return Err(too_many());
+        }
+
+        for point in line.points() {
+            let (chunk, index) = Self::locate(&point);
+
+            self.chunks.entry(chunk).or_insert_with(|| vec![0; cells])[index] += 1;
+        }
+
+        let (min, max) = (line.source.min(&line.dest), line.source.max(&line.dest));
+        self.bounds = Some(match self.bounds {
+            Some((lo, hi)) => (lo.min(&min), hi.max(&max)),
+            None => (min, max),
+        });
+
+        Ok(())
+    }
+
+    /// How many lines cover `point`.
+    pub fn get(&self, point: &Point) -> usize {
+        let (chunk, index) = Self::locate(point);
+
+        self.chunks.get(&chunk).map_or(0, |counts| counts[index])
+    }
+
+    /// The smallest and largest corners of the area the lines cover.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    pub fn num_overlapping(&self) -> usize {
+        self.chunks
+            .values()
+            .flatten()
+            .filter(|&&count| count >= 2)
+            .count()
+    }
+
+    /// Every point covered by a line, with how many lines cover it, from the
+    /// top row down and left to right.
+    pub fn cells(&self) -> Vec<(Point, usize)> {
+        let mut cells = self
+            .chunks
+            .iter()
+            .flat_map(|(&(cx, cy), counts)| {
+                counts
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &count)| count > 0)
+                    .map(move |(index, &count)| {
+                        let (dx, dy) = (index as isize % CHUNK, index as isize / CHUNK);
+
+                        (Point::new(cx * CHUNK + dx, cy * CHUNK + dy), count)
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        cells.sort_by_key(|(point, _)| (point.y, point.x));
+        cells
+    }
+
+    fn max_count(&self) -> usize {
+        self.chunks.values().flatten().copied().max().unwrap_or(0)
+    }
+
+    /// The counts for every point within the bounds, one row at a time,
+    /// unless there are more than `MAX_PIXELS` of them.
+    fn rows(&self) -> Result<Vec<Vec<usize>>> {
+        let (min, max) = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Ok(vec![]),
+        };
+        let width = max.x as i128 - min.x as i128 + 1;
+        let height = max.y as i128 - min.y as i128 + 1;
+
+        if width * height > MAX_PIXELS as i128 {
+            let size = format!("{}x{}", width, height);
+            return Err(Error::new(
+                ErrorKind::LimitExceeded("pixels", MAX_PIXELS),
+                &size,
+            ));
+        }
+
+        Ok((min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| self.get(&Point::new(x, y)))
+                    .collect()
+            })
+            .collect())
+    }
+
+    pub fn render(&self, format: ImageFormat) -> Result<String> {
+        match format {
+            ImageFormat::Ascii => self.to_ascii(),
+            ImageFormat::Pgm => self.to_pgm(),
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Svg => Ok(self.to_svg()),
+        }
+    }
+
+    /// Draws the diagram the way the puzzle does, with `.` where no line is
+    /// and the number of lines elsewhere, or `+` for more than nine.
+    pub fn to_ascii(&self) -> Result<String> {
+        Ok(self
+            .rows()?
+            .iter()
+            .map(|row| {
+                let mut line = row
+                    .iter()
+                    .map(|&count| match count {
+                        0 => '.',
+                        1..=9 => (b'0' + count as u8) as char,
+                        _ => '+',
+                    })
+                    .collect::<String>();
+
+                line.push('\n');
+                line
+            })
+            .collect())
+    }
+
+    /// A plain greyscale image, darker where more lines overlap.
+    pub fn to_pgm(&self) -> Result<String> {
+        let max = self.max_count().max(1);
+
+        self.netpbm("P2", |count| (255 - count * 255 / max).to_string())
+    }
+
+    /// A plain colour image, shaded along `RAMP` by how many lines overlap.
+    pub fn to_ppm(&self) -> Result<String> {
+        let max = self.max_count();
+
+        self.netpbm("P3", |count| {
+            let (r, g, b) = ramp(count, max);
+            format!("{} {} {}", r, g, b)
+        })
+    }
+
+    fn netpbm(&self, magic: &str, pixel: impl Fn(usize) -> String) -> Result<String> {
+        let rows = self.rows()?;
+        let width = rows.first().map_or(0, Vec::len);
+        let mut image = format!("{}\n{} {}\n255\n", magic, width, rows.len());
+
+        for row in &rows {
+            image.push_str(
+                &row.iter()
+                    .map(|&count| pixel(count))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+            image.push('\n');
+        }
+
+        Ok(image)
+    }
+
+    /// One square per covered point, shaded along `RAMP` by how many lines
+    /// overlap there. Empty space is left out, so far apart lines stay cheap,
+    /// and `push` keeps the number of squares within `MAX_PIXELS`.
+    pub fn to_svg(&self) -> String {
+        let (min, max) = self
+            .bounds
+            .unwrap_or((Point::new(0, 0), Point::new(-1, -1)));
+        let max_count = self.max_count();
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" shape-rendering=\"crispEdges\">\n",
+            min.x,
+            min.y,
+            max.x - min.x + 1,
+            max.y - min.y + 1
+        );
+
+        for (point, count) in self.cells() {
+            let (r, g, b) = ramp(count, max_count);
+
+            svg.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                point.x, point.y, r, g, b
+            ));
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// The colours a single line and the most overlapping lines are drawn in.
+pub const RAMP: [(u8, u8, u8); 2] = [(255, 237, 160), (189, 0, 38)];
+
+/// The colour for a point covered by `count` out of at most `max` lines,
+/// or white if none cover it.
+fn ramp(count: usize, max: usize) -> (u8, u8, u8) {
+    if count == 0 {
+        return (255, 255, 255);
+    }
+
+    let t = if max > 1 {
+        (count - 1) as f64 / (max - 1) as f64
+    } else {
+        1.0
+    };
+    let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+    let [(r0, g0, b0), (r1, g1, b1)] = RAMP;
+
+    (mix(r0, r1), mix(g0, g1), mix(b0, b1))
+}
+
+/// The ways a `VentDiagram` can be drawn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ascii,
+    Pgm,
+    Ppm,
+    Svg,
+}
+
+impl ImageFormat {
+    /// Picks the format from the extension of `path`: `txt`, `pgm`, `ppm`
+    /// or `svg`.
+    pub fn from_path(path: &str) -> Option<Self> {
+        match path.rsplit_once('.')?.1.to_ascii_lowercase().as_str() {
+            "txt" => Some(ImageFormat::Ascii),
+            "pgm" => Some(ImageFormat::Pgm),
+            "ppm" => Some(ImageFormat::Ppm),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    const EXAMPLE: [&str; 10] = [
+        "0,9 -> 5,9",
+        "8,0 -> 0,8",
+        "9,4 -> 3,4",
+        "2,2 -> 2,1",
+        "7,0 -> 7,4",
+        "6,4 -> 2,0",
+        "0,9 -> 2,9",
+        "3,4 -> 1,4",
+        "0,0 -> 8,8",
+        "5,5 -> 8,2",
+    ];
+
+    #[test]
+    fn _01_parse() {
+        assert_eq!(
+            EXAMPLE[0].parse::<VentLine>(),
+            Ok(VentLine::new(0, 9, 5, 9))
+        );
+        assert_eq!(
+            EXAMPLE[1].parse::<VentLine>(),
+            Ok(VentLine::new(8, 0, 0, 8))
+        );
+        assert_eq!(
+            EXAMPLE[2].parse::<VentLine>(),
+            Ok(VentLine::new(9, 4, 3, 4))
+        );
+        assert_eq!(
+            EXAMPLE[3].parse::<VentLine>(),
+            Ok(VentLine::new(2, 2, 2, 1))
+        );
+        assert_eq!(
+            EXAMPLE[4].parse::<VentLine>(),
+            Ok(VentLine::new(7, 0, 7, 4))
+        );
+        assert_eq!(
+            EXAMPLE[5].parse::<VentLine>(),
+            Ok(VentLine::new(6, 4, 2, 0))
+        );
+        assert_eq!(
+            EXAMPLE[6].parse::<VentLine>(),
+            Ok(VentLine::new(0, 9, 2, 9))
+        );
+        assert_eq!(
+            EXAMPLE[7].parse::<VentLine>(),
+            Ok(VentLine::new(3, 4, 1, 4))
+        );
+        assert_eq!(
+            EXAMPLE[8].parse::<VentLine>(),
+            Ok(VentLine::new(0, 0, 8, 8))
+        );
+        assert_eq!(
+            EXAMPLE[9].parse::<VentLine>(),
+            Ok(VentLine::new(5, 5, 8, 2))
+        );
+    }
+
+    #[test]
+    fn _01_overlap() {
+        let mut diagram = VentDiagram::new();
+
+        for line in &EXAMPLE {
+            let vent_line = line.parse::<VentLine>().expect("bad vent line");
+
+            if matches!(
+                vent_line.orientation(),
+                Orientation::Horizontal | Orientation::Vertical
+            ) {
+                diagram.push(&vent_line).expect("too many points");
+            }
+        }
+
+        assert_eq!(diagram.num_overlapping(), 5);
+    }
+
+    #[test]
+    fn _02_overlap() {
+        let mut diagram = VentDiagram::new();
+
+        for line in &EXAMPLE {
+            let vent_line = line.parse::<VentLine>().expect("bad vent line");
+            diagram.push(&vent_line).expect("too many points");
+        }
+
+        assert_eq!(diagram.num_overlapping(), 12);
+    }
+
+    #[test]
+    fn analytic() {
+        let lines = EXAMPLE
+            .iter()
+            .map(|line| line.parse::<VentLine>().expect("bad vent line"))
+            .collect::<Vec<_>>();
+        let straight = lines
+            .iter()
+            .filter(|l| {
+                matches!(
+                    l.orientation(),
+                    Orientation::Horizontal | Orientation::Vertical
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(count_overlapping(&straight), 5);
+        assert_eq!(count_overlapping(&lines.iter().collect::<Vec<_>>()), 12);
+    }
+
+    #[test]
+    fn intersection() {
+        let line = VentLine::new(0, 0, 4, 4);
+
+        assert_eq!(
+            line.intersection(&VentLine::new(0, 4, 4, 0)),
+            Some(VentLine::new(2, 2, 2, 2))
+        );
+        assert_eq!(
+            line.intersection(&VentLine::new(6, 6, 3, 3)),
+            Some(VentLine::new(3, 3, 4, 4))
+        );
+        assert_eq!(
+            line.intersection(&VentLine::new(1, 1, 1, 1)),
+            Some(VentLine::new(1, 1, 1, 1))
+        );
+        assert_eq!(line.intersection(&VentLine::new(5, 5, 6, 6)), None);
+        assert_eq!(line.intersection(&VentLine::new(1, 0, 5, 4)), None);
+        assert_eq!(
+            VentLine::new(0, 0, 1, 1).intersection(&VentLine::new(1, 0, 0, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn orientation() {
+        assert_eq!(
+            VentLine::new(0, 9, 5, 9).orientation(),
+            Orientation::Horizontal
+        );
+        assert_eq!(
+            VentLine::new(7, 0, 7, 4).orientation(),
+            Orientation::Vertical
+        );
+        assert_eq!(
+            VentLine::new(8, 0, 0, 8).orientation(),
+            Orientation::Diagonal
+        );
+        assert_eq!(
+            VentLine::new(0, 0, 6, 4).orientation(),
+            Orientation::General
+        );
+        assert_eq!(
+            VentLine::new(3, 3, 3, 3).orientation(),
+            Orientation::Horizontal
+        );
+    }
+
+    #[test]
+    fn general() {
+        let line = VentLine::new(6, 4, 0, 0);
+
+        assert_eq!(
+            line.points().collect::<Vec<_>>(),
+            vec![Point::new(6, 4), Point::new(3, 2), Point::new(0, 0)]
+        );
+        assert_eq!(
+            line.intersection(&VentLine::new(0, 4, 6, 0)),
+            Some(VentLine::new(3, 2, 3, 2))
+        );
+        assert_eq!(
+            VentLine::new(0, 0, 2, 1).intersection(&VentLine::new(1, 0, 1, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn analytic_matches_diagram() {
+        let mut rng = Rng::new(5);
+        let lines = (0..60)
+            .map(|_| {
+                let mut coordinate = || rng.below(16) as isize - 4;
+                VentLine::new(coordinate(), coordinate(), coordinate(), coordinate())
+            })
+            .collect::<Vec<_>>();
+        let mut diagram = VentDiagram::new();
+
+        for line in &lines {
+            diagram.push(line).expect("too many points");
+        }
+
+        assert_eq!(
+            count_overlapping(&lines.iter().collect::<Vec<_>>()),
+            diagram.num_overlapping()
+        );
+    }
+
+    #[test]
+    fn huge() {
+        let lines =
+            Day05::parse(&["0,0 -> 3000000000,3000000001", "0,0 -> 0,5"]).expect("bad vent lines");
+
+        assert_eq!(Day05::part_2(&lines), Some("1".to_string()));
+        assert_eq!(
+            lines[0].intersection(&VentLine::new(3000000000, 0, 3000000000, 3000000001)),
+            Some(VentLine::new(
+                3000000000, 3000000001, 3000000000, 3000000001
+            ))
+        );
+
+        let long = [
+            VentLine::new(0, 0, 3000000000, 0),
+            VentLine::new(1000000000, 0, 4000000000, 0),
+            VentLine::new(-5, 5, 2000000000, 5),
+            VentLine::new(2000000000, 6, -5, 6),
+            VentLine::new(1500000000, -1, 1500000000, 10),
+            VentLine::new(0, 0, 6, 6),
+        ];
+
+        assert_eq!(
+            count_overlapping(&long.iter().collect::<Vec<_>>()),
+            2000000001 + 1 + 1 + 3
+        );
+
+        let err = "0,0 -> 4611686018427387904,0"
+            .parse::<VentLine>()
+            .unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("a smaller coordinate"));
+        assert_eq!(err.column(), Some(8));
+    }
+
+    #[test]
+    fn sparse() {
+        let mut diagram = VentDiagram::new();
+        let line = "-2,-1 -> 2,-1".parse::<VentLine>().expect("bad vent line");
+
+        for line in &[
+            line,
+            VentLine::new(0, -3, 0, 0),
+            VentLine::new(1_000_000_000, 5, 1_000_000_000, 5),
+        ] {
+            diagram.push(line).expect("too many points");
+        }
+
+        assert_eq!(diagram.get(&Point::new(0, -1)), 2);
+        assert_eq!(diagram.get(&Point::new(-2, -1)), 1);
+        assert_eq!(diagram.get(&Point::new(-3, -1)), 0);
+        assert_eq!(diagram.num_overlapping(), 1);
+        assert_eq!(
+            diagram.bounds(),
+            Some((Point::new(-2, -3), Point::new(1_000_000_000, 5)))
+        );
+    }
+
+    #[test]
+    fn _02_ascii() {
+        let mut diagram = VentDiagram::new();
+
+        for line in &EXAMPLE {
+            let line = line.parse::<VentLine>().expect("bad vent line");
+            diagram.push(&line).expect("too many points");
+        }
+
+        assert_eq!(
+            diagram.render(ImageFormat::Ascii).expect("too big"),
+            "1.1....11.\n\
+             .111...2..\n\
+             ..2.1.111.\n\
+             ...1.2.2..\n\
+             .112313211\n\
+             ...1.2....\n\
+             ..1...1...\n\
+             .1.....1..\n\
+             1.......1.\n\
+             222111....\n"
+        );
+
+        let far = VentLine::new(1_000_000_000, 0, 1_000_000_000, 5);
+        diagram.push(&far).expect("too many points");
+        let err = diagram.render(ImageFormat::Pgm).expect_err("rendered");
+        assert_eq!(err.kind(), &ErrorKind::LimitExceeded("pixels", MAX_PIXELS));
+        assert!(diagram.render(ImageFormat::Svg).is_ok());
+    }
+
+    #[test]
+    fn capacity() {
+        let diagram = VentDiagram::with_capacity(MAX_COORDINATE as usize);
+
+        assert!(diagram.chunks.capacity() >= MAX_PIXELS / (CHUNK * CHUNK) as usize);
+        assert!(VentDiagram::with_capacity(usize::MAX).chunks.is_empty());
+        assert!(VentDiagram::with_capacity(0).chunks.capacity() >= 1);
+    }
+
+    #[test]
+    fn too_many_pixels() {
+        let mut diagram = VentDiagram::new();
+        let long = VentLine::new(0, 0, 2_000_000_000, 0);
+        let err = diagram.push(&long).expect_err("drawn");
+
+        assert_eq!(err.kind(), &ErrorKind::LimitExceeded("pixels", MAX_PIXELS));
+        assert_eq!(diagram.bounds(), None);
+
+        // Short enough to hold, but spread over more chunks than it fits in.
+        let diagonal = VentLine::new(0, 0, MAX_PIXELS as isize / 16, MAX_PIXELS as isize / 16);
+        assert!(diagram.push(&diagonal).is_err());
+
+        diagram
+            .push(&VentLine::new(0, 5, 0, 0))
+            .expect("too many points");
+        assert_eq!(diagram.cells().len(), 6);
+    }
+
+    #[test]
+    fn images() {
+        let mut diagram = VentDiagram::new();
+        diagram
+            .push(&VentLine::new(-1, 0, 1, 0))
+            .expect("too many points");
+        diagram
+            .push(&VentLine::new(0, 0, 0, 1))
+            .expect("too many points");
+
+        assert_eq!(
+            diagram.to_pgm(),
+            Ok("P2\n3 2\n255\n128 0 128\n255 128 255\n".to_string())
+        );
+        assert!(diagram
+            .to_ppm()
+            .expect("too big")
+            .starts_with("P3\n3 2\n255\n255 237 160 189 0 38 255 237 160\n"));
+
+        let svg = diagram.to_svg();
+        assert!(svg.contains("viewBox=\"-1 0 3 2\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"#bd0026\"/>"));
+        assert_eq!(svg.matches("<rect").count(), 4);
+
+        assert_eq!(ImageFormat::from_path("vents.SVG"), Some(ImageFormat::Svg));
+        assert_eq!(ImageFormat::from_path("vents"), None);
+        assert_eq!(VentDiagram::new().to_ascii(), Ok(String::new()));
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = "0,9 -> 5,x".parse::<VentLine>().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidNumber);
+        assert_eq!((err.text(), err.column()), ("x", Some(10)));
+
+        let err = "0,9 => 5,9".parse::<VentLine>().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken("->"));
+
+        let err = "0,9 -> 59".parse::<VentLine>().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken(","));
+        assert_eq!(err.column(), Some(8));
+    }
+}
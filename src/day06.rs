@@ -0,0 +1,173 @@
+use crate::error::{parse_lines, parse_number, Result};
+use crate::solver::Solver;
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: usize = 6;
+
+    type Input = Vec<LanternFish>;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        Ok(parse_lines(lines, parse_lanternfish)?.concat())
+    }
+
+    fn part_1(fish: &Self::Input) -> Option<String> {
+        Some(LanternFishSet::new(fish).age(80).len().to_string())
+    }
+
+    fn part_2(fish: &Self::Input) -> Option<String> {
+        Some(LanternFishSet::new(fish).age(256).len().to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LanternFish {
+    internal_timer: usize,
+}
+
+impl LanternFish {
+    pub fn new(internal_timer: usize) -> Self {
+        Self { internal_timer }
+    }
+
+    pub fn try_age(&mut self) -> Option<LanternFish> {
+        if self.internal_timer > 0 {
+            self.internal_timer -= 1;
+
+            None
+        } else {
+            self.internal_timer = 6;
+
+            Some(LanternFish::new(8))
+        }
+    }
+}
+
+pub struct LanternFishSet {
+    fishes: Vec<(LanternFish, usize)>,
+}
+
+impl LanternFishSet {
+    pub fn new(fish: &[LanternFish]) -> Self {
+        let mut set = LanternFishSet { fishes: vec![] };
+        for f in fish {
+            set.add(f, 1);
+        }
+
+        set
+    }
+
+    pub fn age(&mut self, n: usize) -> &Self {
+        for _ in 0..n {
+            let mut new_fish = vec![];
+
+            for (other_fish, count) in self.fishes.iter_mut() {
+                if let Some(f) = other_fish.try_age() {
+                    new_fish.push((f, *count));
+                }
+            }
+
+            for (other_fish, count) in &new_fish {
+                self.add(other_fish, *count);
+            }
+        }
+
+        self
+    }
+
+    pub fn add(&mut self, fish: &LanternFish, count: usize) {
+        for (f, n) in self.fishes.iter_mut() {
+            if f == fish {
+                *n += count;
+                return;
+            }
+        }
+
+        self.fishes.push((fish.clone(), count));
+    }
+
+    pub fn len(&self) -> usize {
+        self.fishes.iter().map(|(_, n)| n).sum::<usize>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+pub fn parse_lanternfish(s: &str) -> Result<Vec<LanternFish>> {
+    s.trim()
+        .split(',')
+        .map(|n| {
+            parse_number::<usize>(n)
+                .map(LanternFish::new)
+                .map_err(|e| e.within(s, n))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3,4,3,1,2";
+
+    #[test]
+    fn _01_parse() {
+        let fish = parse_lanternfish(EXAMPLE).expect("bad example");
+
+        assert_eq!(fish.len(), 5);
+        assert_eq!(fish[0], LanternFish::new(3));
+        assert_eq!(fish[1], LanternFish::new(4));
+        assert_eq!(fish[2], LanternFish::new(3));
+        assert_eq!(fish[3], LanternFish::new(1));
+        assert_eq!(fish[4], LanternFish::new(2));
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = parse_lanternfish("3,4,-3,1,2").unwrap_err();
+
+        assert_eq!((err.text(), err.column()), ("-3", Some(5)));
+    }
+
+    #[test]
+    fn _01_age() {
+        let mut fish = LanternFishSet::new(&parse_lanternfish(EXAMPLE).expect("bad example"));
+
+        assert_eq!(fish.len(), 5);
+        assert_eq!(fish.age(1).len(), 5);
+        assert_eq!(fish.age(1).len(), 6);
+        assert_eq!(fish.age(1).len(), 7);
+        assert_eq!(fish.age(1).len(), 9);
+        assert_eq!(fish.age(1).len(), 10);
+        assert_eq!(fish.age(1).len(), 10);
+        assert_eq!(fish.age(1).len(), 10);
+        assert_eq!(fish.age(1).len(), 10);
+        assert_eq!(fish.age(1).len(), 11);
+        assert_eq!(fish.age(1).len(), 12);
+        assert_eq!(fish.age(1).len(), 15);
+        assert_eq!(fish.age(1).len(), 17);
+        assert_eq!(fish.age(1).len(), 19);
+        assert_eq!(fish.age(1).len(), 20);
+        assert_eq!(fish.age(1).len(), 20);
+        assert_eq!(fish.age(1).len(), 21);
+        assert_eq!(fish.age(1).len(), 22);
+        assert_eq!(fish.age(1).len(), 26);
+    }
+
+    #[test]
+    fn _01_example() {
+        let mut fish = LanternFishSet::new(&parse_lanternfish(EXAMPLE).expect("bad example"));
+
+        assert_eq!(fish.age(80).len(), 5934);
+    }
+
+    #[test]
+    fn _02_example() {
+        let mut fish = LanternFishSet::new(&parse_lanternfish(EXAMPLE).expect("bad example"));
+
+        assert_eq!(fish.age(256).len(), 26984457539);
+    }
+}
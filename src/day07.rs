@@ -0,0 +1,185 @@
+use std::str::FromStr;
+
+use crate::error::{parse_lines, parse_number, Error, Result};
+use crate::solver::Solver;
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: usize = 7;
+
+    type Input = Vec<Crab>;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        Ok(parse_lines(lines, parse_crabs)?.concat())
+    }
+
+    fn part_1(crabs: &Self::Input) -> Option<String> {
+        CrabSet::new(crabs)
+            .min_cost(&SimpleCrabEvaluator::new())
+            .map(|x| x.to_string())
+    }
+
+    fn part_2(crabs: &Self::Input) -> Option<String> {
+        CrabSet::new(crabs)
+            .min_cost(&ComplexCrabEvaluator::new())
+            .map(|x| x.to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crab {
+    horizontal_position: usize,
+}
+
+impl FromStr for Crab {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let horizontal_position = parse_number::<usize>(s)?;
+
+        Ok(Self::new(horizontal_position))
+    }
+}
+
+impl Crab {
+    pub fn new(horizontal_position: usize) -> Self {
+        Self {
+            horizontal_position,
+        }
+    }
+
+    pub fn horizontal_position(&self) -> usize {
+        self.horizontal_position
+    }
+}
+
+pub trait CrabEvaluator {
+    fn cost(&self, crab: &Crab, target_position: usize) -> usize;
+}
+
+#[derive(Default)]
+pub struct SimpleCrabEvaluator;
+
+impl SimpleCrabEvaluator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl CrabEvaluator for SimpleCrabEvaluator {
+    fn cost(&self, crab: &Crab, target_position: usize) -> usize {
+        target_position.abs_diff(crab.horizontal_position)
+    }
+}
+
+#[derive(Default)]
+pub struct ComplexCrabEvaluator;
+
+impl ComplexCrabEvaluator {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn distance(&self, crab: &Crab, target_position: usize) -> usize {
+        target_position.abs_diff(crab.horizontal_position)
+    }
+}
+
+impl CrabEvaluator for ComplexCrabEvaluator {
+    fn cost(&self, crab: &Crab, target_position: usize) -> usize {
+        let distance = self.distance(crab, target_position);
+
+        ((distance + 1) * distance) / 2
+    }
+}
+
+pub struct CrabSet {
+    crabs: Vec<Crab>,
+}
+
+impl CrabSet {
+    pub fn new(crabs: &[Crab]) -> Self {
+        let crabs = crabs.to_vec();
+
+        Self { crabs }
+    }
+
+    pub fn min_cost<Eval: CrabEvaluator>(&self, evaluator: &Eval) -> Option<usize> {
+        let min_position = self.crabs.iter().map(|c| c.horizontal_position).min()?;
+        let max_position = self.crabs.iter().map(|c| c.horizontal_position).max()?;
+
+        (min_position..=max_position)
+            .map(move |target_position| self.cost(evaluator, target_position))
+            .min()
+    }
+
+    pub fn cost<Eval: CrabEvaluator>(&self, evaluator: &Eval, target_position: usize) -> usize {
+        self.crabs
+            .iter()
+            .map(|c| evaluator.cost(c, target_position))
+            .sum::<usize>()
+    }
+}
+
+pub fn parse_crabs(s: &str) -> Result<Vec<Crab>> {
+    s.trim()
+        .split(',')
+        .map(|n| n.parse::<Crab>().map_err(|e| e.within(s, n)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn _01_parse() {
+        let crabs = parse_crabs(EXAMPLE).expect("bad example");
+
+        assert_eq!(crabs.len(), 10);
+        assert_eq!(crabs[0], Crab::new(16));
+        assert_eq!(crabs[1], Crab::new(1));
+        assert_eq!(crabs[2], Crab::new(2));
+        assert_eq!(crabs[3], Crab::new(0));
+        assert_eq!(crabs[4], Crab::new(4));
+        assert_eq!(crabs[5], Crab::new(2));
+        assert_eq!(crabs[6], Crab::new(7));
+        assert_eq!(crabs[7], Crab::new(1));
+        assert_eq!(crabs[8], Crab::new(2));
+        assert_eq!(crabs[9], Crab::new(14));
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = parse_crabs("16,1,2,,4").unwrap_err();
+
+        assert_eq!((err.text(), err.column()), ("", Some(8)));
+    }
+
+    #[test]
+    fn _01_cost() {
+        let crabs = CrabSet::new(&parse_crabs(EXAMPLE).expect("bad example"));
+
+        assert_eq!(crabs.cost(&SimpleCrabEvaluator::new(), 1), 41);
+        assert_eq!(crabs.cost(&SimpleCrabEvaluator::new(), 2), 37);
+        assert_eq!(crabs.cost(&SimpleCrabEvaluator::new(), 10), 71);
+    }
+
+    #[test]
+    fn _01_example() {
+        let crabs = CrabSet::new(&parse_crabs(EXAMPLE).expect("bad example"));
+
+        assert_eq!(crabs.min_cost(&SimpleCrabEvaluator::new()), Some(37));
+    }
+
+    #[test]
+    fn _02_example() {
+        let crabs = CrabSet::new(&parse_crabs(EXAMPLE).expect("bad example"));
+
+        assert_eq!(crabs.cost(&ComplexCrabEvaluator::new(), 2), 206);
+        assert_eq!(crabs.min_cost(&ComplexCrabEvaluator::new()), Some(168));
+    }
+}
@@ -0,0 +1,309 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::error::{parse_lines, Error, ErrorKind, Result};
+use crate::solver::Solver;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: usize = 8;
+
+    type Input = Vec<(Vec<Word>, Vec<Word>)>;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        parse_lines(lines, parse_signals)
+    }
+
+    fn part_1(signals: &Self::Input) -> Option<String> {
+        let mut total = 0;
+
+        for (patterns, output_values) in signals {
+            total += count_num_1478(patterns, output_values)?;
+        }
+
+        Some(total.to_string())
+    }
+
+    fn part_2(signals: &Self::Input) -> Option<String> {
+        let mut total = 0;
+
+        for (patterns, output_values) in signals {
+            let digits = Mapping::solve(patterns)?.map(output_values)?;
+
+            total += 1000 * digits[0] + 100 * digits[1] + 10 * digits[2] + digits[3];
+        }
+
+        Some(total.to_string())
+    }
+}
+
+#[derive(Debug)]
+pub struct Word {
+    parts: Vec<char>,
+}
+
+impl FromStr for Word {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.chars().collect::<Vec<_>>();
+
+        if let Some(i) = parts.iter().position(|ch| !('a'..='g').contains(ch)) {
+            Err(Error::new(ErrorKind::UnexpectedCharacter(parts[i]), s).at_column(i + 1))
+        } else {
+            Ok(Self { parts })
+        }
+    }
+}
+
+impl Word {
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    pub fn parts(&self) -> &[char] {
+        &self.parts
+    }
+}
+
+struct MappedWord<'a, 'b> {
+    word: &'a Word,
+    mapping: &'b [char],
+}
+
+impl<'a, 'b> MappedWord<'a, 'b> {
+    fn new(word: &'a Word, mapping: &'b [char]) -> Self {
+        Self { word, mapping }
+    }
+
+    fn eq(&self, word: &[char]) -> bool {
+        self.word.len() == word.len()
+            && self
+                .word
+                .parts
+                .iter()
+                .all(|&ch| word.contains(&self.mapping[(ch as usize) - 97]))
+    }
+}
+
+fn parse_words(s: &str) -> Result<Vec<Word>> {
+    s.split_whitespace()
+        .map(|word| word.parse::<Word>().map_err(|e| e.within(s, word)))
+        .collect()
+}
+
+pub fn parse_signals(s: &str) -> Result<(Vec<Word>, Vec<Word>)> {
+    let (patterns, outputs) = s
+        .split_once('|')
+        .ok_or_else(|| Error::new(ErrorKind::MissingToken("|"), s))?;
+    let unique_signal_patterns = parse_words(patterns).map_err(|e| e.within(s, patterns))?;
+    let output_values = parse_words(outputs).map_err(|e| e.within(s, outputs))?;
+
+    if output_values.len() != 4 {
+        Err(
+            Error::new(ErrorKind::Malformed("4 output values"), outputs.trim())
+                .within(s, outputs.trim()),
+        )
+    } else {
+        Ok((unique_signal_patterns, output_values))
+    }
+}
+
+pub struct Mapping {
+    wires: Vec<char>,
+}
+
+impl Mapping {
+    const KNOWN_PATTERNS: [&'static [char]; 10] = [
+        &['a', 'b', 'c', 'e', 'f', 'g'],
+        &['c', 'f'],
+        &['a', 'c', 'd', 'e', 'g'],
+        &['a', 'c', 'd', 'f', 'g'],
+        &['b', 'c', 'd', 'f'],
+        &['a', 'b', 'd', 'f', 'g'],
+        &['a', 'b', 'd', 'e', 'f', 'g'],
+        &['a', 'c', 'f'],
+        &['a', 'b', 'c', 'd', 'e', 'f', 'g'],
+        &['a', 'b', 'c', 'd', 'f', 'g'],
+    ];
+
+    fn new(wires: &[char]) -> Self {
+        let wires = wires.to_vec();
+
+        Self { wires }
+    }
+
+    pub fn solve(unique_signal_patterns: &[Word]) -> Option<Self> {
+        let mut wires = vec!['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+
+        while next_permutation(&mut wires) {
+            let mapping = Mapping::new(&wires);
+
+            if mapping.is_consistent(unique_signal_patterns) {
+                return Some(mapping);
+            }
+        }
+
+        None
+    }
+
+    fn is_consistent(&self, unique_signal_patterns: &[Word]) -> bool {
+        for signal_pattern in unique_signal_patterns {
+            let mapped = MappedWord::new(signal_pattern, &self.wires);
+
+            if Self::KNOWN_PATTERNS
+                .iter()
+                .all(|known_pattern| !mapped.eq(known_pattern))
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// The digit each output value shows, or `None` if one of them is not
+    /// a digit under this mapping.
+    pub fn map(&self, output_values: &[Word]) -> Option<Vec<usize>> {
+        output_values
+            .iter()
+            .map(|output_value| {
+                let mapped = MappedWord::new(output_value, &self.wires);
+
+                Self::KNOWN_PATTERNS
+                    .iter()
+                    .enumerate()
+                    .find_map(|(i, known_pattern)| {
+                        if mapped.eq(known_pattern) {
+                            Some(i)
+                        } else {
+                            None
+                        }
+                    })
+            })
+            .collect()
+    }
+}
+
+fn next_permutation(array: &mut [char]) -> bool {
+    let last_ascending = match array.windows(2).rposition(|w| w[0] < w[1]) {
+        Some(i) => i,
+        None => {
+            array.reverse();
+            return false;
+        }
+    };
+
+    let swap_with = array[last_ascending + 1..]
+        .binary_search_by(|n| char::cmp(&array[last_ascending], n).then(Ordering::Less))
+        .unwrap_err(); // cannot fail because the binary search will never succeed
+    array.swap(last_ascending, last_ascending + swap_with);
+    array[last_ascending + 1..].reverse();
+    true
+}
+
+pub fn count_num_1478(unique_signal_patterns: &[Word], output_values: &[Word]) -> Option<usize> {
+    let mapping = Mapping::solve(unique_signal_patterns)?;
+    let mut count = 0;
+
+    for output_value in mapping.map(output_values)? {
+        if output_value == 1 || output_value == 4 || output_value == 7 || output_value == 8 {
+            count += 1;
+        }
+    }
+
+    Some(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE_EXAMPLE: &str =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    const EXAMPLE: [&str; 10] = [
+        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe",
+        "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc",
+        "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg",
+        "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb",
+        "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea",
+        "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb",
+        "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe",
+        "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef",
+        "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb",
+        "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+    ];
+
+    #[test]
+    fn _01_parse() {
+        let (patterns, output_values) = parse_signals(EXAMPLE[0]).expect("bad example");
+
+        assert_eq!(patterns.len(), 10);
+        assert_eq!(patterns[0].parts, vec!['b', 'e']);
+        assert_eq!(output_values.len(), 4);
+        assert_eq!(
+            output_values[0].parts,
+            vec!['f', 'd', 'g', 'a', 'c', 'b', 'e']
+        );
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = parse_signals("be cfbegad | fdgacbe cefdx cefbgd gcbe").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnexpectedCharacter('x'));
+        assert_eq!((err.text(), err.column()), ("cefdx", Some(26)));
+
+        let err = parse_signals("be cfbegad fdgacbe cefdb cefbgd gcbe").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken("|"));
+
+        let err = parse_signals("be cfbegad | fdgacbe cefdb cefbgd").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("4 output values"));
+    }
+
+    #[test]
+    fn _01_example() {
+        assert_eq!(
+            (0..10)
+                .map(|i| {
+                    let (patterns, output_values) = parse_signals(EXAMPLE[i]).expect("bad example");
+
+                    count_num_1478(&patterns, &output_values).expect("no solution")
+                })
+                .sum::<usize>(),
+            26
+        );
+    }
+
+    #[test]
+    fn _02_solve() {
+        let (patterns, output_values) = parse_signals(LINE_EXAMPLE).expect("bad example");
+        let mapping = Mapping::solve(&patterns).unwrap();
+
+        assert_eq!(mapping.map(&output_values), Some(vec![5, 3, 5, 3]));
+        assert_eq!(mapping.map(&["a".parse().expect("bad word")]), None);
+    }
+
+    #[test]
+    fn _02_example() {
+        let total = EXAMPLE
+            .iter()
+            .map(|line| {
+                let (patterns, output_values) = parse_signals(line).expect("bad example");
+                let digits = Mapping::solve(&patterns)
+                    .unwrap()
+                    .map(&output_values)
+                    .expect("not a digit");
+
+                1000 * digits[0] + 100 * digits[1] + 10 * digits[2] + digits[3]
+            })
+            .sum::<usize>();
+
+        assert_eq!(total, 61229);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solver::Solver;
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: usize = 9;
+
+    type Input = HeightMap;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        HeightMap::parse(lines)
+    }
+
+    fn part_1(height_map: &Self::Input) -> Option<String> {
+        Some(
+            height_map
+                .coords()
+                .iter()
+                .filter(|(x, y)| height_map.is_low_point(*x, *y))
+                .filter_map(|(x, y)| height_map.at(*x, *y).map(|h| h + 1))
+                .sum::<usize>()
+                .to_string(),
+        )
+    }
+
+    fn part_2(height_map: &Self::Input) -> Option<String> {
+        let mut basins = height_map.basin_sizes();
+        basins.sort_unstable();
+
+        Some(basins.iter().rev().take(3).product::<usize>().to_string())
+    }
+}
+
+pub struct HeightMap {
+    floor: Grid<usize>,
+}
+
+impl HeightMap {
+    pub fn parse(lines: &[&str]) -> Result<Self> {
+        Ok(Self {
+            floor: Grid::parse_digits(lines)?,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.floor.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.floor.height()
+    }
+
+    pub fn coords(&self) -> Vec<(usize, usize)> {
+        self.floor.coords().collect()
+    }
+
+    pub fn basin_size(&self, x: usize, y: usize) -> usize {
+        let mut visited = Grid::from_elem(self.width(), self.height(), false);
+        let mut to_visit = vec![(x, y)];
+        let mut size = 0;
+
+        while let Some((x, y)) = to_visit.pop() {
+            if visited[(x, y)] {
+                // pass
+            } else if self.floor[(x, y)] < 9 {
+                visited[(x, y)] = true;
+                size += 1;
+                to_visit.extend(self.floor.neighbours_4(x, y));
+            }
+        }
+
+        size
+    }
+
+    pub fn basin_sizes(&self) -> Vec<usize> {
+        self.coords()
+            .iter()
+            .filter(|(x, y)| self.is_low_point(*x, *y))
+            .map(|(x, y)| self.basin_size(*x, *y))
+            .collect::<Vec<_>>()
+    }
+
+    pub fn is_low_point(&self, x: usize, y: usize) -> bool {
+        self.at(x, y)
+            .map(|h| {
+                self.floor
+                    .neighbours_4(x, y)
+                    .all(|neighbour| self.floor[neighbour] > h)
+            })
+            .unwrap_or(false)
+    }
+
+    pub fn at(&self, x: usize, y: usize) -> Option<usize> {
+        self.floor.get(x, y).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 5] = [
+        "2199943210",
+        "3987894921",
+        "9856789892",
+        "8767896789",
+        "9899965678",
+    ];
+
+    #[test]
+    fn _01_parse() {
+        let height_map = HeightMap::parse(&EXAMPLE).expect("missing height_map");
+
+        assert_eq!(height_map.width(), 10);
+        assert_eq!(height_map.height(), 5);
+        assert_eq!(
+            height_map.floor.iter().copied().collect::<Vec<_>>(),
+            vec![
+                2, 1, 9, 9, 9, 4, 3, 2, 1, 0, 3, 9, 8, 7, 8, 9, 4, 9, 2, 1, 9, 8, 5, 6, 7, 8, 9, 8,
+                9, 2, 8, 7, 6, 7, 8, 9, 6, 7, 8, 9, 9, 8, 9, 9, 9, 6, 5, 6, 7, 8,
+            ]
+        );
+    }
+
+    #[test]
+    fn _01_example() {
+        let height_map = HeightMap::parse(&EXAMPLE).expect("missing height_map");
+
+        assert_eq!(
+            height_map
+                .coords()
+                .iter()
+                .filter(|(x, y)| height_map.is_low_point(*x, *y))
+                .filter_map(|(x, y)| height_map.at(*x, *y).map(|h| h + 1))
+                .sum::<usize>(),
+            15
+        );
+    }
+
+    #[test]
+    fn _02_basin_size() {
+        let height_map = HeightMap::parse(&EXAMPLE).expect("missing height_map");
+
+        assert_eq!(height_map.basin_size(1, 0), 3);
+        assert_eq!(height_map.basin_size(9, 0), 9);
+        assert_eq!(height_map.basin_size(2, 2), 14);
+        assert_eq!(height_map.basin_size(7, 4), 9);
+    }
+
+    #[test]
+    fn _02_example() {
+        let height_map = HeightMap::parse(&EXAMPLE).expect("missing height_map");
+        let mut basins = height_map.basin_sizes();
+        basins.sort();
+
+        assert_eq!(basins.iter().rev().take(3).product::<usize>(), 1134);
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solver::Solver;
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: usize = 11;
+
+    type Input = Octopuses;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        Octopuses::parse(lines)
+    }
+
+    fn part_1(octopuses: &Self::Input) -> Option<String> {
+        let mut octopuses = octopuses.clone();
+
+        Some(
+            (0..100)
+                .map(|_| octopuses.age().flash().0.reset().1)
+                .sum::<usize>()
+                .to_string(),
+        )
+    }
+
+    fn part_2(octopuses: &Self::Input) -> Option<String> {
+        let mut octopuses = octopuses.clone();
+
+        (1..usize::MAX)
+            .find(|_| {
+                let (_, count) = octopuses.age().flash();
+                octopuses.reset();
+
+                count == octopuses.len()
+            })
+            .map(|step| step.to_string())
+    }
+}
+
+#[derive(Clone, Default)]
+struct Octopus {
+    energy_level: usize,
+    has_flashed: bool,
+}
+
+impl Octopus {
+    fn new(n: usize) -> Self {
+        Self {
+            energy_level: n,
+            has_flashed: false,
+        }
+    }
+
+    fn age(&mut self) {
+        self.energy_level += 1;
+    }
+
+    fn flashed(&mut self) {
+        self.energy_level += 1;
+    }
+
+    fn try_flash(&mut self) -> bool {
+        if self.energy_level > 9 && !self.has_flashed {
+            self.has_flashed = true;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn reset(&mut self) {
+        if self.has_flashed {
+            self.energy_level = 0;
+            self.has_flashed = false;
+        }
+    }
+}
+
+impl Display for Octopus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.energy_level)
+    }
+}
+
+#[derive(Clone)]
+pub struct Octopuses {
+    octopuses: Grid<Octopus>,
+}
+
+impl Octopuses {
+    fn new(octopuses: Grid<Octopus>) -> Self {
+        Self { octopuses }
+    }
+
+    pub fn parse(lines: &[&str]) -> Result<Octopuses> {
+        let energy_levels = Grid::parse_digits(lines)?;
+
+        Ok(Octopuses::new(energy_levels.map(|&n| Octopus::new(n))))
+    }
+
+    pub fn len(&self) -> usize {
+        self.octopuses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.octopuses.is_empty()
+    }
+
+    pub fn age(&mut self) -> &mut Self {
+        for octopus in self.octopuses.iter_mut() {
+            octopus.age();
+        }
+
+        self
+    }
+
+    fn try_flash(&mut self) -> usize {
+        let to_flash = self
+            .octopuses
+            .coords()
+            .filter(|&coord| self.octopuses[coord].try_flash())
+            .collect::<Vec<_>>();
+
+        for &(x, y) in &to_flash {
+            for neighbour in self.octopuses.neighbours_8(x, y) {
+                self.octopuses[neighbour].flashed();
+            }
+        }
+
+        to_flash.len()
+    }
+
+    pub fn flash(&mut self) -> (&mut Self, usize) {
+        let mut total_count = 0;
+
+        loop {
+            let count = self.try_flash();
+
+            if count == 0 {
+                break;
+            }
+
+            total_count += count;
+        }
+
+        (self, total_count)
+    }
+
+    pub fn reset(&mut self) -> (&mut Self, usize) {
+        let mut count = 0;
+
+        for octopus in self.octopuses.iter_mut() {
+            if octopus.has_flashed {
+                count += 1;
+            }
+
+            octopus.reset();
+        }
+
+        (self, count)
+    }
+}
+
+impl Display for Octopuses {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.octopuses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 10] = [
+        "5483143223",
+        "2745854711",
+        "5264556173",
+        "6141336146",
+        "6357385478",
+        "4167524645",
+        "2176841721",
+        "6882881134",
+        "4846848554",
+        "5283751526",
+    ];
+
+    #[test]
+    fn _01_parse() {
+        let octopuses = Octopuses::parse(&EXAMPLE).expect("no octopuses");
+
+        assert_eq!(
+            format!("{}", octopuses),
+            "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526\n"
+        );
+    }
+
+    #[test]
+    fn _01_flash() {
+        let mut octopuses =
+            Octopuses::parse(&["11111", "19991", "19191", "19991", "11111"]).expect("no octopuses");
+
+        assert_eq!(
+            format!("{}", octopuses.age().flash().0.reset().0),
+            "34543\n40004\n50005\n40004\n34543\n"
+        );
+        assert_eq!(
+            format!("{}", octopuses.age().flash().0.reset().0),
+            "45654\n51115\n61116\n51115\n45654\n"
+        );
+    }
+
+    #[test]
+    fn _01_example_10() {
+        let mut octopuses = Octopuses::parse(&EXAMPLE).expect("no octopuses");
+
+        assert_eq!(
+            (0..10)
+                .map(|_| octopuses.age().flash().0.reset().1)
+                .sum::<usize>(),
+            204
+        );
+    }
+
+    #[test]
+    fn _01_example_100() {
+        let mut octopuses = Octopuses::parse(&EXAMPLE).expect("no octopuses");
+
+        assert_eq!(
+            (0..100)
+                .map(|_| octopuses.age().flash().0.reset().1)
+                .sum::<usize>(),
+            1656
+        );
+    }
+
+    #[test]
+    fn _02_example() {
+        let mut octopuses = Octopuses::parse(&EXAMPLE).expect("no octopuses");
+
+        assert_eq!(
+            (1..usize::MAX).find(|_| {
+                let (_, count) = octopuses.age().flash();
+                octopuses.reset();
+
+                count == octopuses.len()
+            }),
+            Some(195)
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use crate::error::{Error, ErrorKind, Result};
+use crate::graph::Graph;
+use crate::solver::Solver;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: usize = 12;
+
+    type Input = CaveSystem;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        CaveSystem::parse(lines)
+    }
+
+    fn part_1(caves: &Self::Input) -> Option<String> {
+        Some(traverse(caves, SimpleLimiter::new()).len().to_string())
+    }
+
+    fn part_2(caves: &Self::Input) -> Option<String> {
+        Some(traverse(caves, ComplexLimiter::new()).len().to_string())
+    }
+}
+
+pub trait Limiter {
+    fn is_valid(&self, path: &Path, candidate: usize) -> bool;
+}
+
+#[derive(Default)]
+pub struct SimpleLimiter;
+
+impl SimpleLimiter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Limiter for SimpleLimiter {
+    fn is_valid(&self, path: &Path, candidate: usize) -> bool {
+        let limit = if path.caves.is_big(candidate) {
+            usize::MAX
+        } else {
+            1
+        };
+
+        path.occurences[candidate] < limit
+    }
+}
+
+#[derive(Default)]
+pub struct ComplexLimiter;
+
+impl ComplexLimiter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Limiter for ComplexLimiter {
+    fn is_valid(&self, path: &Path, candidate: usize) -> bool {
+        let limit = if path.caves.is_big(candidate) {
+            usize::MAX
+        } else if path.caves.is_special(candidate) {
+            1
+        } else if path.all_lowercase_unique() {
+            2
+        } else {
+            1
+        };
+
+        path.occurences[candidate] < limit
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Cave {
+    is_special: bool,
+    is_big: bool,
+}
+
+impl Cave {
+    fn parse(name: &str) -> Result<Self> {
+        if let Some((i, ch)) = name
+            .chars()
+            .enumerate()
+            .find(|(_, ch)| !ch.is_ascii_alphabetic())
+        {
+            return Err(Error::new(ErrorKind::UnexpectedCharacter(ch), name).at_column(i + 1));
+        } else if name.is_empty() {
+            return Err(Error::new(ErrorKind::Malformed("a cave name"), name));
+        }
+
+        Ok(Self {
+            is_special: name == "start" || name == "end",
+            is_big: name.chars().all(char::is_uppercase),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CaveSystem {
+    caves: Vec<Cave>,
+    tunnels: Graph,
+}
+
+impl CaveSystem {
+    pub fn is_big(&self, index: usize) -> bool {
+        self.caves[index].is_big
+    }
+
+    pub fn is_special(&self, index: usize) -> bool {
+        self.caves[index].is_special
+    }
+
+    pub fn len(&self) -> usize {
+        self.caves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.caves.is_empty()
+    }
+
+    pub fn name(&self, index: usize) -> &str {
+        self.tunnels.name(index).expect("unnamed cave")
+    }
+
+    pub fn index_of(&self, cave: &str) -> Option<usize> {
+        self.tunnels.node(cave)
+    }
+
+    /// The caves as nodes of a graph, with a unit-weight edge for each
+    /// direction of every tunnel.
+    pub fn tunnels(&self) -> &Graph {
+        &self.tunnels
+    }
+
+    fn index_or_insert(&mut self, line: &str, name: &str) -> Result<usize> {
+        if let Some(idx) = self.index_of(name) {
+            Ok(idx)
+        } else {
+            self.caves
+                .push(Cave::parse(name).map_err(|e| e.within(line, name))?);
+            Ok(self.tunnels.intern(name))
+        }
+    }
+
+    pub fn parse(lines: &[&str]) -> Result<Self> {
+        let mut cave_system = Self {
+            caves: Vec::with_capacity(2 * lines.len()),
+            tunnels: Graph::new(),
+        };
+
+        for (i, line) in lines.iter().enumerate() {
+            let (src, dst) = line
+                .split_once('-')
+                .ok_or_else(|| Error::new(ErrorKind::MissingToken("-"), line).at_line(i + 1))?;
+            let src_idx = cave_system
+                .index_or_insert(line, src)
+                .map_err(|e| e.at_line(i + 1))?;
+            let dst_idx = cave_system
+                .index_or_insert(line, dst)
+                .map_err(|e| e.at_line(i + 1))?;
+
+            if !cave_system.tunnels.has_edge(src_idx, dst_idx) {
+                cave_system.tunnels.add_undirected_edge(src_idx, dst_idx, 1);
+            }
+        }
+
+        if cave_system.index_of("start").is_none() || cave_system.index_of("end").is_none() {
+            Err(Error::new(
+                ErrorKind::Malformed("a `start` and an `end` cave"),
+                "",
+            ))
+        } else {
+            Ok(cave_system)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Path<'a> {
+    caves: &'a CaveSystem,
+    visited: Vec<usize>,
+    occurences: Vec<usize>,
+}
+
+impl<'a> Path<'a> {
+    fn new(caves: &'a CaveSystem, index: usize) -> Self {
+        let mut occurences = vec![0; caves.len()];
+        occurences[index] += 1;
+
+        Self {
+            caves,
+            visited: vec![index],
+            occurences,
+        }
+    }
+
+    pub fn all_lowercase_unique(&self) -> bool {
+        for &i in &self.visited {
+            if !self.caves.is_big(i) && self.occurences[i] > 1 {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    pub fn visited(&self) -> &[usize] {
+        &self.visited
+    }
+
+    pub fn occurrences(&self, index: usize) -> usize {
+        self.occurences[index]
+    }
+
+    pub fn last(&self) -> usize {
+        self.visited[self.visited.len() - 1]
+    }
+
+    fn with_cave(&self, index: usize) -> Self {
+        let mut new_path = self.clone();
+        new_path.visited.push(index);
+        new_path.occurences[index] += 1;
+        new_path
+    }
+}
+
+pub fn traverse(cave_system: &CaveSystem, limit: impl Limiter) -> Vec<Path<'_>> {
+    let starting_point = cave_system.index_of("start").expect("no starting point");
+    let end_point = cave_system.index_of("end").expect("no end point");
+
+    let mut to_probe = vec![Path::new(cave_system, starting_point)];
+    let mut paths = vec![];
+
+    while let Some(next_path) = to_probe.pop() {
+        let i = next_path.last();
+
+        if i == end_point {
+            paths.push(next_path);
+        } else {
+            for (j, _) in cave_system.tunnels.neighbours(i) {
+                if limit.is_valid(&next_path, j) {
+                    to_probe.push(next_path.with_cave(j));
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: [&str; 7] = ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
+
+    const EXAMPLE_2: [&str; 10] = [
+        "dc-end", "HN-start", "start-kj", "dc-start", "dc-HN", "LN-dc", "HN-end", "kj-sa", "kj-HN",
+        "kj-dc",
+    ];
+
+    const EXAMPLE_3: [&str; 18] = [
+        "fs-end", "he-DX", "fs-he", "start-DX", "pj-DX", "end-zg", "zg-sl", "zg-pj", "pj-he",
+        "RW-he", "fs-DX", "pj-RW", "zg-RW", "start-pj", "he-WI", "zg-he", "pj-fs", "start-RW",
+    ];
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = CaveSystem::parse(&["start-A", "A_end"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken("-"));
+        assert_eq!(err.line(), Some(2));
+
+        let err = CaveSystem::parse(&["start-A", "A-3nd"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnexpectedCharacter('3'));
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+
+        let err = CaveSystem::parse(&["start-A"]).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::Malformed("a `start` and an `end` cave")
+        );
+    }
+
+    #[test]
+    fn _01_example_1() {
+        let cave_system = CaveSystem::parse(&EXAMPLE_1).expect("no caves");
+        assert_eq!(cave_system.caves.len(), 6);
+        assert_eq!(traverse(&cave_system, SimpleLimiter::new()).len(), 10);
+    }
+
+    #[test]
+    fn _01_example_2() {
+        let cave_system = CaveSystem::parse(&EXAMPLE_2).expect("no caves");
+        assert_eq!(cave_system.caves.len(), 7);
+        assert_eq!(traverse(&cave_system, SimpleLimiter::new()).len(), 19);
+    }
+
+    #[test]
+    fn _01_example_3() {
+        let cave_system = CaveSystem::parse(&EXAMPLE_3).expect("no caves");
+        assert_eq!(traverse(&cave_system, SimpleLimiter::new()).len(), 226);
+    }
+
+    #[test]
+    fn _02_example_1() {
+        let cave_system = CaveSystem::parse(&EXAMPLE_1).expect("no caves");
+        assert_eq!(traverse(&cave_system, ComplexLimiter::new()).len(), 36);
+    }
+
+    #[test]
+    fn _02_example_2() {
+        let cave_system = CaveSystem::parse(&EXAMPLE_2).expect("no caves");
+        assert_eq!(traverse(&cave_system, ComplexLimiter::new()).len(), 103);
+    }
+
+    #[test]
+    fn _02_example_3() {
+        let cave_system = CaveSystem::parse(&EXAMPLE_3).expect("no caves");
+        assert_eq!(traverse(&cave_system, ComplexLimiter::new()).len(), 3509);
+    }
+
+    #[test]
+    fn _01_tunnels() {
+        let cave_system = CaveSystem::parse(&EXAMPLE_1).expect("no caves");
+        let start = cave_system.index_of("start").expect("no start");
+        let end = cave_system.index_of("end").expect("no end");
+        let path = cave_system
+            .tunnels()
+            .bfs(start)
+            .path_to(end)
+            .expect("no path");
+
+        assert_eq!(path.cost, 2);
+        assert_eq!(cave_system.name(path.nodes[1]), "A");
+    }
+}
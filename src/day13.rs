@@ -0,0 +1,226 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::error::{parse_number, Error, ErrorKind, Result};
+use crate::grid::Grid;
+use crate::solver::Solver;
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: usize = 13;
+
+    type Input = (Paper, Vec<Instruction>);
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        parse_manual(lines)
+    }
+
+    fn part_1((paper, instr): &Self::Input) -> Option<String> {
+        Some(instr.first()?.fold(paper).count().to_string())
+    }
+
+    fn part_2((paper, instr): &Self::Input) -> Option<String> {
+        Some(
+            instr
+                .iter()
+                .fold(paper.clone(), |acc, i| i.fold(&acc))
+                .to_string(),
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Paper {
+    dots: Grid<bool>,
+}
+
+impl Display for Paper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.dots.map(|&dot| if dot { '#' } else { '.' }))
+    }
+}
+
+impl Default for Paper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Paper {
+    pub fn new() -> Self {
+        let dots = Grid::from_elem(0, 0, false);
+
+        Self { dots }
+    }
+
+    pub fn count(&self) -> usize {
+        self.dots.iter().filter(|b| **b).count()
+    }
+
+    pub fn set(&mut self, x: usize, y: usize) {
+        if x >= self.dots.width() || y >= self.dots.height() {
+            let width = self.dots.width().max(x + 1);
+            let height = self.dots.height().max(y + 1);
+
+            self.dots.resize(width, height, false);
+        }
+
+        self.dots[(x, y)] = true;
+    }
+
+    pub fn fold_left(&self, offset: usize) -> Self {
+        let dots = Grid::from_fn(offset, self.dots.height(), |x, y| {
+            self.dots[(x, y)] || self.dots.get(2 * offset - x, y) == Some(&true)
+        });
+
+        Self { dots }
+    }
+
+    pub fn fold_up(&self, offset: usize) -> Self {
+        let dots = Grid::from_fn(self.dots.width(), offset, |x, y| {
+            self.dots[(x, y)] || self.dots.get(x, 2 * offset - y) == Some(&true)
+        });
+
+        Self { dots }
+    }
+
+    pub fn parse<'a>(lines: impl Iterator<Item = (usize, &'a str)>) -> Result<Self> {
+        let mut paper = Self::new();
+
+        for (i, line) in lines {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| Error::new(ErrorKind::MissingToken(","), line))
+                .and_then(|(x, y)| {
+                    Ok((
+                        parse_number::<usize>(x).map_err(|e| e.within(line, x))?,
+                        parse_number::<usize>(y).map_err(|e| e.within(line, y))?,
+                    ))
+                })
+                .map_err(|e| e.at_line(i + 1))?;
+
+            paper.set(x, y);
+        }
+
+        Ok(paper)
+    }
+}
+
+#[derive(Debug)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    along_axis: Axis,
+    offset: usize,
+}
+
+impl Instruction {
+    pub fn parse<'a>(lines: impl Iterator<Item = (usize, &'a str)>) -> Result<Vec<Self>> {
+        let mut instr = vec![];
+
+        for (i, line) in lines.filter(|(_, line)| !line.is_empty()) {
+            let (along_axis, offset) = if let Some(offset) = line.strip_prefix("fold along y=") {
+                (Axis::Y, offset)
+            } else if let Some(offset) = line.strip_prefix("fold along x=") {
+                (Axis::X, offset)
+            } else {
+                return Err(Error::new(ErrorKind::UnrecognizedToken, line).at_line(i + 1));
+            };
+            let offset =
+                parse_number::<usize>(offset).map_err(|e| e.within(line, offset).at_line(i + 1))?;
+
+            instr.push(Instruction { along_axis, offset });
+        }
+
+        Ok(instr)
+    }
+
+    pub fn fold(&self, paper: &Paper) -> Paper {
+        match self.along_axis {
+            Axis::X => paper.fold_left(self.offset),
+            Axis::Y => paper.fold_up(self.offset),
+        }
+    }
+}
+
+pub fn parse_manual(lines: &[&str]) -> Result<(Paper, Vec<Instruction>)> {
+    let lines = lines.iter().copied().enumerate();
+    let paper = Paper::parse(lines.clone().take_while(|(_, s)| !s.is_empty()))?;
+    let instr = Instruction::parse(lines.skip_while(|(_, s)| !s.is_empty()))?;
+
+    Ok((paper, instr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 21] = [
+        "6,10",
+        "0,14",
+        "9,10",
+        "0,3",
+        "10,4",
+        "4,11",
+        "6,0",
+        "6,12",
+        "4,1",
+        "0,13",
+        "10,12",
+        "3,4",
+        "3,0",
+        "8,4",
+        "1,10",
+        "2,14",
+        "8,10",
+        "9,0",
+        "",
+        "fold along y=7",
+        "fold along x=5",
+    ];
+
+    #[test]
+    fn _01_parse() {
+        let (paper, instr) = parse_manual(&EXAMPLE).expect("no manual");
+
+        assert_eq!((paper.dots.width(), paper.dots.height()), (11, 15));
+        assert_eq!(instr.len(), 2);
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = parse_manual(&["6,10", "0;14", "", "fold along y=7"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken(","));
+        assert_eq!(err.line(), Some(2));
+
+        let err = parse_manual(&["6,10", "", "fold along y=7", "fold along z=5"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnrecognizedToken);
+        assert_eq!(err.line(), Some(4));
+
+        let err = parse_manual(&["6,10", "", "fold along x=five"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidNumber);
+        assert_eq!((err.line(), err.column()), (Some(3), Some(14)));
+    }
+
+    #[test]
+    fn _01_example() {
+        let (paper, instr) = parse_manual(&EXAMPLE).expect("no manual");
+
+        assert_eq!(instr[0].fold(&paper).count(), 17);
+        assert_eq!(instr.iter().fold(paper, |acc, i| i.fold(&acc)).count(), 16);
+    }
+
+    #[test]
+    fn _02_example() {
+        let (paper, instr) = parse_manual(&EXAMPLE).expect("no manual");
+
+        assert_eq!(
+            format!("{}", instr.iter().fold(paper, |acc, i| i.fold(&acc))),
+            "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n"
+        );
+    }
+}
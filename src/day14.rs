@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::solver::Solver;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: usize = 14;
+
+    type Input = (Polymer, Vec<PairInsertionRule>);
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        parse_polymerization(lines)
+    }
+
+    fn part_1((polymer, rules): &Self::Input) -> Option<String> {
+        Some(polymer_strength(polymer, rules, 10).to_string())
+    }
+
+    fn part_2((polymer, rules): &Self::Input) -> Option<String> {
+        Some(polymer_strength(polymer, rules, 40).to_string())
+    }
+}
+
+pub fn polymer_strength(polymer: &Polymer, rules: &[PairInsertionRule], steps: usize) -> usize {
+    let polymer = (0..steps).fold(polymer.clone(), |polymer, _| polymerize(&polymer, rules));
+    let occurences = polymer.count();
+    let most_common = occurences.values().max().expect("no most common");
+    let least_common = occurences.values().min().expect("no least common");
+
+    most_common - least_common
+}
+
+#[derive(Clone, Debug)]
+pub struct Polymer {
+    pairs: HashMap<[char; 2], usize>,
+    end_token: char,
+}
+
+impl FromStr for Polymer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(Error::new(ErrorKind::Empty, s));
+        }
+
+        Ok(Self::new(&s.chars().collect::<Vec<_>>(), 1))
+    }
+}
+
+impl Polymer {
+    pub fn new(s: &[char], count: usize) -> Self {
+        let mut pairs = HashMap::new();
+
+        if s.is_empty() {
+            Self {
+                pairs,
+                end_token: '\0',
+            }
+        } else {
+            let end_token = s[s.len() - 1];
+
+            for offset in 0..(s.len() - 1) {
+                let pair = [s[offset], s[offset + 1]];
+
+                *pairs.entry(pair).or_insert(0) += count;
+            }
+
+            Self { pairs, end_token }
+        }
+    }
+
+    pub fn merge(polymers: &[Polymer], end_token: char) -> Polymer {
+        let mut pairs = HashMap::new();
+
+        for polymer in polymers {
+            for (&pair, count) in polymer.iter() {
+                *pairs.entry(pair).or_insert(0) += count;
+            }
+        }
+
+        Self { pairs, end_token }
+    }
+
+    pub fn count(&self) -> HashMap<char, usize> {
+        let mut occurences = self
+            .pairs
+            .iter()
+            .fold(HashMap::new(), |mut acc, (ch, &count)| {
+                *acc.entry(ch[0]).or_insert(0) += count;
+                acc
+            });
+
+        *occurences.entry(self.end_token).or_insert(0) += 1;
+        occurences
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&[char; 2], &usize)> {
+        self.pairs.iter()
+    }
+}
+
+#[derive(Debug)]
+pub struct PairInsertionRule {
+    pattern: [char; 2],
+    to_insert: char,
+}
+
+impl FromStr for PairInsertionRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pattern, to_insert) = s
+            .split_once("->")
+            .ok_or_else(|| Error::new(ErrorKind::MissingToken("->"), s))?;
+        let (pattern, to_insert) = (pattern.trim(), to_insert.trim());
+
+        match (
+            pattern.chars().collect::<Vec<_>>().as_slice(),
+            to_insert.chars().collect::<Vec<_>>().as_slice(),
+        ) {
+            (&[a, b], &[c]) => Ok(Self {
+                pattern: [a, b],
+                to_insert: c,
+            }),
+            (&[_, _], _) => Err(
+                Error::new(ErrorKind::Malformed("a single element"), to_insert)
+                    .within(s, to_insert),
+            ),
+            _ => Err(
+                Error::new(ErrorKind::Malformed("a pair of elements"), pattern).within(s, pattern),
+            ),
+        }
+    }
+}
+
+impl PairInsertionRule {
+    pub fn pattern(&self) -> [char; 2] {
+        self.pattern
+    }
+
+    pub fn to_insert(&self) -> char {
+        self.to_insert
+    }
+
+    pub fn matches(&self, s: &[char]) -> bool {
+        s.len() >= 2 && s[0] == self.pattern[0] && s[1] == self.pattern[1]
+    }
+}
+
+pub fn parse_polymerization(lines: &[&str]) -> Result<(Polymer, Vec<PairInsertionRule>)> {
+    let polymer = lines
+        .first()
+        .ok_or_else(|| Error::new(ErrorKind::Empty, ""))?
+        .parse::<Polymer>()
+        .map_err(|e| e.at_line(1))?;
+
+    match lines.get(1) {
+        Some(line) if !line.is_empty() => {
+            Err(Error::new(ErrorKind::Malformed("a blank line"), line).at_line(2))
+        }
+        _ => {
+            let rules = lines
+                .iter()
+                .enumerate()
+                .skip(2)
+                .map(|(i, line)| line.parse().map_err(|e: Error| e.at_line(i + 1)))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok((polymer, rules))
+        }
+    }
+}
+
+pub fn polymerize(polymer: &Polymer, rules: &[PairInsertionRule]) -> Polymer {
+    Polymer::merge(
+        &polymer
+            .iter()
+            .map(|(pair, &count)| {
+                let mut new_polymer = vec![pair[0]];
+
+                for rule in rules {
+                    if rule.matches(pair) {
+                        new_polymer.push(rule.to_insert);
+                    }
+                }
+
+                new_polymer.push(pair[1]);
+                Polymer::new(&new_polymer, count)
+            })
+            .collect::<Vec<_>>(),
+        polymer.end_token,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [&str; 18] = [
+        "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B", "HN -> C",
+        "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B", "CC -> N",
+        "CN -> C",
+    ];
+
+    #[test]
+    fn _01_occurences() {
+        let (polymer, _rules) = parse_polymerization(&EXAMPLE).expect("bad example");
+        let occurences = polymer.count();
+
+        assert_eq!(occurences[&'N'], 2);
+        assert_eq!(occurences[&'C'], 1);
+        assert_eq!(occurences[&'B'], 1);
+    }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = parse_polymerization(&["NNCB", "", "CH -> B", "HH => N"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken("->"));
+        assert_eq!(err.line(), Some(4));
+
+        let err = parse_polymerization(&["NNCB", "", "CHH -> B"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("a pair of elements"));
+        assert_eq!((err.line(), err.column()), (Some(3), Some(1)));
+
+        let err = parse_polymerization(&["NNCB", "", "CH -> "]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("a single element"));
+
+        let err = parse_polymerization(&[]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Empty);
+    }
+
+    #[test]
+    fn _01_example() {
+        let (polymer, rules) = parse_polymerization(&EXAMPLE).expect("bad example");
+        let polymer = (0..10).fold(polymer, |polymer, _| polymerize(&polymer, &rules));
+        let occurences = polymer.count();
+
+        assert_eq!(occurences[&'B'], 1749);
+        assert_eq!(occurences[&'C'], 298);
+        assert_eq!(occurences[&'H'], 161);
+        assert_eq!(occurences[&'N'], 865);
+
+        let most_common = occurences.values().max().expect("no most common");
+        let least_common = occurences.values().min().expect("no least common");
+
+        assert_eq!(most_common - least_common, 1588);
+        assert_eq!(polymer_strength(&polymer, &rules, 0), 1588);
+    }
+
+    #[test]
+    fn _02_example() {
+        let (polymer, rules) = parse_polymerization(&EXAMPLE).expect("bad example");
+        assert_eq!(polymer_strength(&polymer, &rules, 40), 2188189693529);
+    }
+}
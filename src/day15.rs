@@ -0,0 +1,172 @@
+use crate::error::Result;
+use crate::graph::Graph;
+use crate::grid::Grid;
+use crate::solver::Solver;
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: usize = 15;
+
+    type Input = Maze;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input> {
+        Maze::parse(lines)
+    }
+
+    fn part_1(maze: &Self::Input) -> Option<String> {
+        maze.shortest_path_tb().map(|risk| risk.to_string())
+    }
+
+    fn part_2(maze: &Self::Input) -> Option<String> {
+        maze.tile(5).shortest_path_tb().map(|risk| risk.to_string())
+    }
+}
+
+pub struct Maze {
+    risk_level: Grid<usize>,
+}
+
+impl Maze {
+    /// Parses one risk level from 1 to 9 per position.
+    pub fn parse(lines: &[&str]) -> Result<Self> {
+        let risk_level = Grid::parse(lines, |ch| {
+            ch.to_digit(10).filter(|&d| d > 0).map(|d| d as usize)
+        })?;
+
+        Ok(Self { risk_level })
+    }
+
+    pub fn tile(&self, n: usize) -> Maze {
+        let w = self.risk_level.width();
+        let h = self.risk_level.height();
+        let risk_level = Grid::from_fn(n * w, n * h, |x, y| {
+            let offset = x / w + y / h;
+
+            (self.risk_level[(x % w, y % h)] + offset - 1) % 9 + 1
+        });
+
+        Self { risk_level }
+    }
+
+    pub fn top_left(&self) -> (usize, usize) {
+        (0, 0)
+    }
+
+    pub fn bottom_right(&self) -> (usize, usize) {
+        (self.risk_level.width() - 1, self.risk_level.height() - 1)
+    }
+
+    pub fn shortest_path_tb(&self) -> Option<usize> {
+        self.shortest_path(self.top_left(), self.bottom_right())
+    }
+
+    fn node(&self, (x, y): (usize, usize)) -> usize {
+        y * self.risk_level.width() + x
+    }
+
+    /// Every position as a node, with an edge to each of its neighbours
+    /// weighted by the risk level of entering that neighbour.
+    pub fn graph(&self) -> Graph {
+        let mut graph = Graph::new();
+
+        for _ in 0..self.risk_level.len() {
+            graph.add_node();
+        }
+
+        for (x, y) in self.risk_level.coords() {
+            for neighbour in self.risk_level.neighbours_4(x, y) {
+                graph.add_edge(
+                    self.node((x, y)),
+                    self.node(neighbour),
+                    self.risk_level[neighbour],
+                );
+            }
+        }
+
+        graph
+    }
+
+    pub fn shortest_path(
+        &self,
+        starting_point: (usize, usize),
+        end_point: (usize, usize),
+    ) -> Option<usize> {
+        // every step costs at least 1, so the distance never overestimates
+        let heuristic = |node: usize| {
+            let (x, y) = (
+                node % self.risk_level.width(),
+                node / self.risk_level.width(),
+            );
+
+            end_point.0.abs_diff(x) + end_point.1.abs_diff(y)
+        };
+
+        self.graph()
+            .a_star(self.node(starting_point), self.node(end_point), heuristic)
+            .map(|path| path.cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    const EXAMPLE: [&str; 10] = [
+        "1163751742",
+        "1381373672",
+        "2136511328",
+        "3694931569",
+        "7463417111",
+        "1319128137",
+        "1359912421",
+        "3125421639",
+        "1293138521",
+        "2311944581",
+    ];
+
+    #[test]
+    fn _01_parse() {
+        let maze = Maze::parse(&EXAMPLE);
+        assert!(maze.is_ok());
+    }
+
+    #[test]
+    fn _01_zero_risk() {
+        let err = Maze::parse(&["00000", "11110", "00000", "01111", "00000"])
+            .err()
+            .expect("zero risk accepted");
+
+        assert_eq!(err.kind(), &ErrorKind::UnexpectedCharacter('0'));
+        assert_eq!((err.line(), err.column()), (Some(1), Some(1)));
+    }
+
+    #[test]
+    fn _01_example() {
+        let maze = Maze::parse(&EXAMPLE).expect("no maze");
+
+        assert_eq!(maze.shortest_path_tb(), Some(40));
+    }
+
+    #[test]
+    fn _02_tile() {
+        let maze = Maze::parse(&["1"]).expect("no maze");
+
+        assert_eq!(maze.tile(3).risk_level.to_string(), "123\n234\n345\n");
+    }
+
+    #[test]
+    fn _02_tile_wrap() {
+        let maze = Maze::parse(&["9"]).expect("no maze");
+
+        assert_eq!(maze.tile(3).risk_level.to_string(), "912\n123\n234\n");
+    }
+
+    #[test]
+    fn _02_example() {
+        let maze = Maze::parse(&EXAMPLE).expect("no maze").tile(5);
+
+        assert_eq!(maze.shortest_path_tb(), Some(315));
+    }
+}
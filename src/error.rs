@@ -0,0 +1,210 @@
+use std::error;
+use std::fmt::{self, Display, Formatter};
+use std::result;
+use std::str::FromStr;
+
+pub type Result<T, E = Error> = result::Result<T, E>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ErrorKind {
+    Empty,
+    InvalidNumber,
+    UnexpectedCharacter(char),
+    MissingToken(&'static str),
+    UnrecognizedToken,
+    TooFewTokens,
+    TooManyTokens,
+    Malformed(&'static str),
+    Incomparable,
+    /// More of something than the given limit allows.
+    LimitExceeded(&'static str, usize),
+    NoSolution,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::UnexpectedCharacter(ch) => write!(f, "unexpected character `{}`", ch),
+            Self::MissingToken(token) => write!(f, "missing `{}`", token),
+            Self::UnrecognizedToken => write!(f, "unrecognized token"),
+            Self::TooFewTokens => write!(f, "too few tokens"),
+            Self::TooManyTokens => write!(f, "too many tokens"),
+            Self::Malformed(expected) => write!(f, "expected {}", expected),
+            Self::Incomparable => write!(f, "incomparable reading"),
+            Self::LimitExceeded(what, limit) => write!(f, "more than {} {}", limit, what),
+            Self::NoSolution => write!(f, "no solution"),
+        }
+    }
+}
+
+/// A problem with the puzzle input, pointing at where it was found. The day,
+/// line and column are filled in by whoever knows them, so an error raised
+/// by a `FromStr` only carries its column until the caller adds the line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Error {
+    kind: ErrorKind,
+    text: String,
+    day: Option<usize>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            day: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn day(&self) -> Option<usize> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn on_day(mut self, day: usize) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Set the (one-based) line number, unless it is already known.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Set the (one-based) column, unless it is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Move the column of an error raised while parsing `inner` so that it
+    /// is relative to `outer` instead, where `inner` is a slice of `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let offset = offset_of(outer, inner);
+
+        self.column = Some(offset + self.column.unwrap_or(1));
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let location = [
+            ("day", self.day),
+            ("line", self.line),
+            ("column", self.column),
+        ]
+        .iter()
+        .filter_map(|(name, n)| n.map(|n| format!("{} {}", name, n)))
+        .collect::<Vec<_>>();
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+
+        write!(f, "{}", self.kind)?;
+        if !self.text.is_empty() {
+            write!(f, " in `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for Error {}
+
+/// Returns the number of characters in `outer` before `inner` starts, or
+/// zero if `inner` is not a slice of `outer`.
+fn offset_of(outer: &str, inner: &str) -> usize {
+    let start = outer.as_ptr() as usize;
+    let position = inner.as_ptr() as usize;
+
+    if position >= start && position <= start + outer.len() {
+        outer[..(position - start)].chars().count()
+    } else {
+        0
+    }
+}
+
+/// Parses a number, reporting the offending text if it is not one.
+pub fn parse_number<T: FromStr>(s: &str) -> Result<T> {
+    s.trim()
+        .parse::<T>()
+        .map_err(|_| Error::new(ErrorKind::InvalidNumber, s.trim()).within(s, s.trim()))
+}
+
+/// Parses every line with `parse`, attaching the line number to any error.
+pub fn parse_lines<T>(lines: &[&str], parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let err = Error::new(ErrorKind::InvalidNumber, "12a")
+            .at_column(3)
+            .at_line(7)
+            .on_day(1);
+
+        assert_eq!(
+            err.to_string(),
+            "day 1, line 7, column 3: invalid number in `12a`"
+        );
+    }
+
+    #[test]
+    fn within() {
+        let line = "0,9 -> 5,x";
+        let point = &line[7..];
+        let err = Error::new(ErrorKind::InvalidNumber, "x").within(point, &point[2..]);
+
+        assert_eq!(err.column(), Some(3));
+        assert_eq!(err.within(line, point).column(), Some(10));
+    }
+
+    #[test]
+    fn parse_number_with_whitespace() {
+        assert_eq!(parse_number::<usize>(" 42 "), Ok(42));
+        assert_eq!(
+            parse_number::<usize>("  4x").map_err(|e| e.column()),
+            Err(Some(3))
+        );
+    }
+
+    #[test]
+    fn parse_lines_reports_line() {
+        let err = parse_lines(&["1", "2", "x"], parse_number::<usize>).unwrap_err();
+
+        assert_eq!(err.line(), Some(3));
+        assert_eq!(err.kind(), &ErrorKind::InvalidNumber);
+    }
+}
@@ -0,0 +1,128 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::windowed::Windowed;
+
+/// A way of smoothing a series of readings before comparing them, written
+/// as `sum:<width>`, `mean:<width>`, `median:<width>` or `ema:<alpha>`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Filter {
+    Sum(usize),
+    Mean(usize),
+    Median(usize),
+    /// Exponential smoothing, giving each new reading a weight of `alpha`.
+    Exponential(f64),
+}
+
+impl Filter {
+    /// Smooths `readings`, yielding one value per full window, or one per
+    /// reading for exponential smoothing.
+    pub fn apply<'a>(
+        &self,
+        readings: impl Iterator<Item = f64> + 'a,
+    ) -> Box<dyn Iterator<Item = f64> + 'a> {
+        match *self {
+            Filter::Sum(width) => Box::new(Windowed::new(readings, width).map(|w| w.iter().sum())),
+            Filter::Mean(width) => Box::new(
+                Windowed::new(readings, width).map(|w| w.iter().sum::<f64>() / w.len() as f64),
+            ),
+            Filter::Median(width) => {
+                let mut sorted = Vec::with_capacity(width);
+
+                Box::new(Windowed::new(readings, width).map(move |w| {
+                    sorted.clear();
+                    sorted.extend_from_slice(w);
+                    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+                    let mid = sorted.len() / 2;
+                    if sorted.len() % 2 == 0 {
+                        (sorted[mid - 1] + sorted[mid]) / 2.0
+                    } else {
+                        sorted[mid]
+                    }
+                }))
+            }
+            Filter::Exponential(alpha) => Box::new(readings.scan(None, move |smoothed, x| {
+                let next = match *smoothed {
+                    Some(s) => alpha * x + (1.0 - alpha) * s,
+                    None => x,
+                };
+
+                *smoothed = Some(next);
+                Some(next)
+            })),
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, arg) = s.trim().split_once(':').ok_or_else(|| s.to_string())?;
+        let width = || arg.parse::<usize>().ok().filter(|&n| n > 0);
+
+        match name {
+            "sum" => width().map(Filter::Sum),
+            "mean" => width().map(Filter::Mean),
+            "median" => width().map(Filter::Median),
+            "ema" => arg
+                .parse::<f64>()
+                .ok()
+                .filter(|&alpha| alpha > 0.0 && alpha <= 1.0)
+                .map(Filter::Exponential),
+            _ => None,
+        }
+        .ok_or_else(|| s.to_string())
+    }
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Filter::Sum(width) => write!(f, "sum:{}", width),
+            Filter::Mean(width) => write!(f, "mean:{}", width),
+            Filter::Median(width) => write!(f, "median:{}", width),
+            Filter::Exponential(alpha) => write!(f, "ema:{}", alpha),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn smooth(filter: Filter, readings: &[f64]) -> Vec<f64> {
+        filter.apply(readings.iter().copied()).collect()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("median:5".parse::<Filter>(), Ok(Filter::Median(5)));
+        assert_eq!("ema:0.5".parse::<Filter>(), Ok(Filter::Exponential(0.5)));
+        assert_eq!("sum:0".parse::<Filter>(), Err("sum:0".to_string()));
+        assert_eq!("ema:2".parse::<Filter>(), Err("ema:2".to_string()));
+        assert_eq!("sum".parse::<Filter>(), Err("sum".to_string()));
+        assert_eq!(Filter::Mean(3).to_string(), "mean:3");
+    }
+
+    #[test]
+    fn windows() {
+        let readings = [1.0, 5.0, 3.0, 2.0];
+
+        assert_eq!(smooth(Filter::Sum(2), &readings), vec![6.0, 8.0, 5.0]);
+        assert_eq!(smooth(Filter::Mean(2), &readings), vec![3.0, 4.0, 2.5]);
+        assert_eq!(smooth(Filter::Median(3), &readings), vec![3.0, 3.0]);
+        assert_eq!(smooth(Filter::Median(4), &readings), vec![2.5]);
+    }
+
+    #[test]
+    fn exponential() {
+        assert_eq!(
+            smooth(Filter::Exponential(0.5), &[4.0, 8.0, 0.0]),
+            vec![4.0, 6.0, 3.0]
+        );
+        assert!(smooth(Filter::Exponential(0.5), &[]).is_empty());
+    }
+}
@@ -0,0 +1,316 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::ops::Add;
+
+pub type NodeId = usize;
+
+/// A directed graph stored as adjacency lists, with optional names for the
+/// nodes. Undirected graphs are built by adding both directions.
+#[derive(Clone, Debug)]
+pub struct Graph<W = usize> {
+    names: Vec<Option<String>>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn new() -> Self {
+        Self {
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    /// Adds a node without a name.
+    pub fn add_node(&mut self) -> NodeId {
+        self.names.push(None);
+        self.edges.push(vec![]);
+        self.edges.len() - 1
+    }
+
+    /// Returns the node with the given name, adding it first if necessary.
+    pub fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            id
+        } else {
+            let id = self.add_node();
+
+            self.names[id] = Some(name.to_string());
+            self.ids.insert(name.to_string(), id);
+            id
+        }
+    }
+
+    pub fn node(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> Option<&str> {
+        self.names.get(id)?.as_deref()
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.edges[from].iter().any(|&(id, _)| id == to)
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &W)> {
+        self.edges[id].iter().map(|(to, weight)| (*to, weight))
+    }
+
+    /// Visits nodes in order of the number of edges from `source`, ignoring
+    /// the weights.
+    pub fn bfs(&self, source: NodeId) -> Paths<usize> {
+        let mut paths = Paths::new(self.len(), source, 0);
+        let mut to_visit = VecDeque::from(vec![source]);
+
+        while let Some(id) = to_visit.pop_front() {
+            let hops = paths.distance[id].expect("visited");
+
+            for (to, _) in self.neighbours(id) {
+                if paths.distance[to].is_none() {
+                    paths.distance[to] = Some(hops + 1);
+                    paths.previous[to] = Some(id);
+                    to_visit.push_back(to);
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+impl<W: Clone> Graph<W> {
+    pub fn add_undirected_edge(&mut self, a: NodeId, b: NodeId, weight: W) {
+        self.add_edge(a, b, weight.clone());
+        self.add_edge(b, a, weight);
+    }
+}
+
+impl<W: Copy + Default + Ord + Add<Output = W>> Graph<W> {
+    /// The cheapest paths from `source` to every node it can reach.
+    pub fn dijkstra(&self, source: NodeId) -> Paths<W> {
+        let mut paths = Paths::new(self.len(), source, W::default());
+        let mut to_visit = BinaryHeap::from(vec![Reverse((W::default(), source))]);
+
+        while let Some(Reverse((cost, id))) = to_visit.pop() {
+            if paths.distance[id].map(|best| cost > best).unwrap_or(false) {
+                continue;
+            }
+
+            for (to, &weight) in self.neighbours(id) {
+                let next = cost + weight;
+
+                if paths.distance[to].map(|best| next < best).unwrap_or(true) {
+                    paths.distance[to] = Some(next);
+                    paths.previous[to] = Some(id);
+                    to_visit.push(Reverse((next, to)));
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// The cheapest path from `source` to `target`, guided by a `heuristic`
+    /// that must never overestimate the remaining cost to `target`. A node
+    /// is expanded again whenever a cheaper way to it turns up, so the
+    /// heuristic need not be consistent.
+    pub fn a_star(
+        &self,
+        source: NodeId,
+        target: NodeId,
+        heuristic: impl Fn(NodeId) -> W,
+    ) -> Option<Path<W>> {
+        let mut paths = Paths::new(self.len(), source, W::default());
+        let mut to_visit =
+            BinaryHeap::from(vec![Reverse((heuristic(source), source, W::default()))]);
+
+        while let Some(Reverse((_, id, cost))) = to_visit.pop() {
+            if paths.distance[id].map(|best| cost > best).unwrap_or(false) {
+                continue;
+            } else if id == target {
+                return paths.path_to(target);
+            }
+
+            for (to, &weight) in self.neighbours(id) {
+                let next = cost + weight;
+
+                if paths.distance[to].map(|best| next < best).unwrap_or(true) {
+                    paths.distance[to] = Some(next);
+                    paths.previous[to] = Some(id);
+                    to_visit.push(Reverse((next + heuristic(to), to, next)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// The result of a search from a single source: how far away every node is
+/// and which node it was reached from.
+#[derive(Clone, Debug)]
+pub struct Paths<W> {
+    source: NodeId,
+    distance: Vec<Option<W>>,
+    previous: Vec<Option<NodeId>>,
+}
+
+impl<W: Copy> Paths<W> {
+    fn new(len: usize, source: NodeId, zero: W) -> Self {
+        let mut distance = vec![None; len];
+        distance[source] = Some(zero);
+
+        Self {
+            source,
+            distance,
+            previous: vec![None; len],
+        }
+    }
+
+    pub fn source(&self) -> NodeId {
+        self.source
+    }
+
+    pub fn distance(&self, target: NodeId) -> Option<W> {
+        self.distance.get(target).copied().flatten()
+    }
+
+    pub fn path_to(&self, target: NodeId) -> Option<Path<W>> {
+        let cost = self.distance(target)?;
+        let mut nodes = vec![target];
+
+        while let Some(previous) = self.previous[*nodes.last()?] {
+            nodes.push(previous);
+        }
+
+        nodes.reverse();
+        Some(Path { cost, nodes })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<W> {
+    pub cost: W,
+    pub nodes: Vec<NodeId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //  a --1-- b --1-- c
+    //   \             /
+    //    -----5------
+    fn triangle() -> Graph {
+        let mut graph = Graph::new();
+        let (a, b, c) = (graph.intern("a"), graph.intern("b"), graph.intern("c"));
+
+        graph.add_undirected_edge(a, b, 1);
+        graph.add_undirected_edge(b, c, 1);
+        graph.add_undirected_edge(a, c, 5);
+        graph
+    }
+
+    #[test]
+    fn intern() {
+        let mut graph = triangle();
+
+        assert_eq!(graph.intern("b"), 1);
+        assert_eq!(graph.node("c"), Some(2));
+        assert_eq!(graph.node("d"), None);
+        assert_eq!(graph.name(0), Some("a"));
+        assert_eq!(graph.len(), 3);
+
+        let id = graph.add_node();
+        assert_eq!(graph.name(id), None);
+    }
+
+    #[test]
+    fn bfs() {
+        let graph = triangle();
+        let paths = graph.bfs(0);
+
+        assert_eq!(paths.distance(2), Some(1));
+        assert_eq!(paths.path_to(2).map(|p| p.nodes), Some(vec![0, 2]));
+    }
+
+    #[test]
+    fn dijkstra() {
+        let mut graph = triangle();
+        let d = graph.intern("d");
+        let paths = graph.dijkstra(0);
+
+        assert_eq!(
+            paths.path_to(2),
+            Some(Path {
+                cost: 2,
+                nodes: vec![0, 1, 2]
+            })
+        );
+        assert_eq!(paths.distance(d), None);
+        assert_eq!(paths.path_to(d), None);
+    }
+
+    #[test]
+    fn a_star() {
+        let graph = triangle();
+
+        assert_eq!(
+            graph.a_star(0, 2, |_| 0),
+            Some(Path {
+                cost: 2,
+                nodes: vec![0, 1, 2]
+            })
+        );
+        assert_eq!(graph.a_star(2, 2, |_| 0).map(|p| p.cost), Some(0));
+    }
+
+    #[test]
+    fn a_star_inconsistent() {
+        // s --1--> a --1--> b --5--> t, with a dearer edge from s to b.
+        let mut graph = Graph::new();
+        let (s, a, b, t) = (
+            graph.add_node(),
+            graph.add_node(),
+            graph.add_node(),
+            graph.add_node(),
+        );
+
+        graph.add_edge(s, a, 1);
+        graph.add_edge(a, b, 1);
+        graph.add_edge(s, b, 3);
+        graph.add_edge(b, t, 5);
+
+        // Never more than the true remaining cost, but it drops by more than
+        // the edge from a to b, so b is first reached the dear way.
+        let heuristic = |id| if id == a { 6 } else { 0 };
+
+        assert_eq!(
+            graph.a_star(s, t, heuristic),
+            Some(Path {
+                cost: 7,
+                nodes: vec![s, a, b, t]
+            })
+        );
+    }
+}
@@ -0,0 +1,348 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, ErrorKind, Result};
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, addressed by `(x, y)` with the origin in
+/// the top left corner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>, // row-major
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f(x, y));
+            }
+        }
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let height = rows.len();
+
+        if let Some(i) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::new(ErrorKind::Malformed("rows of equal width"), "").at_line(i + 1));
+        }
+
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Parses one cell per character, with one row per line.
+    pub fn parse(lines: &[&str], f: impl Fn(char) -> Option<T>) -> Result<Self> {
+        let mut rows = Vec::with_capacity(lines.len());
+
+        for (i, line) in lines.iter().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(j, ch)| {
+                    f(ch).ok_or_else(|| {
+                        Error::new(ErrorKind::UnexpectedCharacter(ch), line)
+                            .at_column(j + 1)
+                            .at_line(i + 1)
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            if row.is_empty() {
+                return Err(Error::new(ErrorKind::Empty, line).at_line(i + 1));
+            }
+
+            rows.push(row);
+        }
+
+        if rows.is_empty() {
+            return Err(Error::new(ErrorKind::Empty, ""));
+        }
+
+        Self::from_rows(rows).map_err(|e| {
+            let line = e.line().and_then(|i| lines.get(i - 1)).unwrap_or(&"");
+
+            Error::new(e.kind().clone(), line).at_line(e.line().unwrap_or(0))
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Like `get`, but for coordinates that may have stepped off the grid.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.get(x as usize, y as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        (0..self.len()).map(move |i| (i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The up to four cells sharing an edge with `(x, y)`.
+    pub fn neighbours_4(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(self, x, y, &NEIGHBOURS_4)
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `(x, y)`.
+    pub fn neighbours_8(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(self, x, y, &NEIGHBOURS_8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn from_elem(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Changes the size of the grid, keeping every cell that still fits at
+    /// the same coordinates and filling the new ones with `value`.
+    pub fn resize(&mut self, width: usize, height: usize, value: T) {
+        let mut cells = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self.get(x, y).unwrap_or(&value).clone());
+            }
+        }
+
+        self.cells = cells;
+        self.width = width;
+        self.height = height;
+    }
+}
+
+impl Grid<usize> {
+    pub fn parse_digits(lines: &[&str]) -> Result<Self> {
+        Self::parse(lines, |ch| ch.to_digit(10).map(|d| d as usize))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("out of bounds")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Neighbours {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    offsets: &'static [(isize, isize)],
+}
+
+impl Neighbours {
+    fn new<T>(grid: &Grid<T>, x: usize, y: usize, offsets: &'static [(isize, isize)]) -> Self {
+        Self {
+            x,
+            y,
+            width: grid.width,
+            height: grid.height,
+            offsets,
+        }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((&(dx, dy), rest)) = self.offsets.split_first() {
+            self.offsets = rest;
+
+            let x = self.x as isize + dx;
+            let y = self.y as isize + dy;
+
+            if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+                return Some((x as usize, y as usize));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_digits() {
+        let grid = Grid::parse_digits(&["123", "456"]).expect("no grid");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get(0, 1), Some(&4));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Grid::parse_digits(&["12", "3a"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnexpectedCharacter('a'));
+        assert_eq!((err.line(), err.column()), (Some(2), Some(2)));
+
+        let err = Grid::parse_digits(&["12", "345"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("rows of equal width"));
+        assert_eq!((err.line(), err.text()), (Some(2), "345"));
+
+        let err = Grid::parse_digits(&[]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Empty);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::from_elem(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours_4(1, 1).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (2, 1), (1, 2)]
+        );
+        assert_eq!(
+            grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbours_8(2, 2).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn resize() {
+        let mut grid = Grid::from_fn(2, 2, |x, y| x + 10 * y);
+
+        grid.resize(3, 1, 9);
+        assert_eq!(grid, Grid::from_rows(vec![vec![0, 1, 9]]).expect("no grid"));
+
+        grid.resize(2, 2, 7);
+        assert_eq!(
+            grid,
+            Grid::from_rows(vec![vec![0, 1], vec![7, 7]]).expect("no grid")
+        );
+    }
+
+    #[test]
+    fn coords() {
+        let grid = Grid::from_fn(2, 2, |x, y| (x, y));
+
+        assert!(grid.indexed_iter().all(|(coord, cell)| coord == *cell));
+    }
+}
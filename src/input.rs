@@ -1,5 +1,153 @@
-use std::io::{self, BufRead};
+use flate2::read::MultiGzDecoder;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor};
+use std::path::{Path, PathBuf};
+
+/// Where to read a puzzle input from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Input {
+    File(PathBuf),
+    GzipFile(PathBuf),
+    Memory(String),
+    Stdin,
+}
+
+impl Input {
+    pub fn file(path: impl AsRef<Path>) -> Self {
+        Self::File(path.as_ref().to_path_buf())
+    }
+
+    pub fn gzip_file(path: impl AsRef<Path>) -> Self {
+        Self::GzipFile(path.as_ref().to_path_buf())
+    }
+
+    pub fn memory(s: impl Into<String>) -> Self {
+        Self::Memory(s.into())
+    }
+
+    pub fn stdin() -> Self {
+        Self::Stdin
+    }
+
+    /// A file, decompressed on the fly if its name ends in `.gz`.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+
+        if path.extension().map(|ext| ext == "gz").unwrap_or(false) {
+            Self::gzip_file(path)
+        } else {
+            Self::file(path)
+        }
+    }
+
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead + '_>> {
+        Ok(match self {
+            Self::File(path) => Box::new(BufReader::new(File::open(path)?)),
+            // Concatenated gzip files decompress to their contents joined
+            // together, so read every member rather than just the first.
+            Self::GzipFile(path) => {
+                Box::new(BufReader::new(MultiGzDecoder::new(File::open(path)?)))
+            }
+            Self::Memory(s) => Box::new(Cursor::new(s.as_bytes())),
+            Self::Stdin => Box::new(io::stdin().lock()),
+        })
+    }
+
+    /// Streams the input one line at a time, without reading all of it first.
+    pub fn stream(&self) -> io::Result<Lines<'_>> {
+        Ok(Lines {
+            lines: self.reader()?.lines(),
+        })
+    }
+
+    pub fn lines(&self) -> io::Result<Vec<String>> {
+        self.stream()?.collect()
+    }
+}
+
+pub struct Lines<'a> {
+    lines: io::Lines<Box<dyn BufRead + 'a>>,
+}
+
+impl Iterator for Lines<'_> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next()
+    }
+}
 
 pub fn lines() -> io::Result<Vec<String>> {
-    io::stdin().lock().lines().collect()
+    Input::stdin().lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+
+    #[test]
+    fn from_path() {
+        assert_eq!(Input::from_path("01.txt"), Input::File("01.txt".into()));
+        assert_eq!(
+            Input::from_path("01.txt.gz"),
+            Input::GzipFile("01.txt.gz".into())
+        );
+    }
+
+    #[test]
+    fn memory() {
+        let input = Input::memory("199\n200\r\n208\n");
+
+        assert_eq!(input.lines().expect("no lines"), vec!["199", "200", "208"]);
+    }
+
+    #[test]
+    fn stream() {
+        let input = Input::memory("a\nb\nc");
+        let mut lines = input.stream().expect("no lines");
+
+        assert_eq!(lines.next().map(Result::ok), Some(Some("a".to_string())));
+        assert_eq!(lines.count(), 2);
+    }
+
+    #[test]
+    fn gzip_file() {
+        let path = env::temp_dir().join(format!("aoc-2021-input-{}.txt.gz", std::process::id()));
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"16,1,2\n").expect("not written");
+        fs::write(&path, encoder.finish().expect("not compressed")).expect("not written");
+
+        let lines = Input::from_path(&path).lines();
+        fs::remove_file(&path).expect("not removed");
+
+        assert_eq!(lines.expect("no lines"), vec!["16,1,2"]);
+    }
+
+    #[test]
+    fn gzip_members() {
+        let path = env::temp_dir().join(format!("aoc-2021-members-{}.txt.gz", std::process::id()));
+        let mut members = vec![];
+
+        for part in &[&b"16,1,2\n0,"[..], b"4\n"] {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(part).expect("not written");
+            members.extend(encoder.finish().expect("not compressed"));
+        }
+        fs::write(&path, members).expect("not written");
+
+        let lines = Input::from_path(&path).lines();
+        fs::remove_file(&path).expect("not removed");
+
+        assert_eq!(lines.expect("no lines"), vec!["16,1,2", "0,4"]);
+    }
+
+    #[test]
+    fn missing_file() {
+        assert!(Input::file("does/not/exist.txt").lines().is_err());
+    }
 }
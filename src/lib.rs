@@ -1,5 +1,37 @@
+pub mod answers;
+pub mod bench;
+pub mod bingo_odds;
+pub mod course;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod error;
+pub mod filter;
+pub mod graph;
+pub mod grid;
 pub mod input;
 pub mod ordering_iter;
+pub mod output;
+pub mod rng;
+pub mod solver;
 pub mod submarine;
 pub mod submarine_command;
+pub mod trajectory;
 pub mod windowed;
+
+pub use error::Error;
@@ -1,18 +1,100 @@
 use std::cmp::Ordering;
 
+use crate::error::{Error, ErrorKind, Result};
+
+/// What to do with a measurement that cannot be compared, such as a NaN.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Incomparable {
+    /// Leave it out, comparing the next measurement with the one before it.
+    Skip,
+    /// Treat it as a gap in the series, so there is no step across it.
+    Gap,
+    /// Stop, making the error available from `OrderingIter::error`.
+    Error,
+}
+
+/// The direction of each step from one measurement to the next.
 pub struct OrderingIter<T: PartialOrd + Clone, I: Iterator<Item = T>> {
-    previous_measurement: Option<T>,
+    previous_measurement: Option<(usize, T)>,
     measurements: I,
+    policy: Incomparable,
+    index: usize,
+    incomparable: Vec<usize>,
+    error: Option<Error>,
 }
 
 impl<T: PartialOrd + Clone, I: Iterator<Item = T>> OrderingIter<T, I> {
-    pub fn new(mut measurements: I) -> Self {
-        let previous_measurement = measurements.next();
+    /// Stops at the first measurement that cannot be compared.
+    pub fn new(measurements: I) -> Self {
+        Self::with_policy(measurements, Incomparable::Error)
+    }
 
+    pub fn with_policy(measurements: I, policy: Incomparable) -> Self {
         OrderingIter {
-            previous_measurement: previous_measurement,
+            previous_measurement: None,
             measurements,
+            policy,
+            index: 0,
+            incomparable: vec![],
+            error: None,
+        }
+    }
+
+    /// The indexes of the measurements that could not be compared so far.
+    pub fn incomparable(&self) -> &[usize] {
+        &self.incomparable
+    }
+
+    /// Why iteration stopped early, if it did.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Like `next`, but with the measurements either side of the step.
+    fn next_step(&mut self) -> Option<Step<T>> {
+        while self.error.is_none() {
+            let curr = self.measurements.next()?;
+            let index = self.index;
+            self.index += 1;
+
+            let valid = curr.partial_cmp(&curr).is_some();
+            let ordering = match &self.previous_measurement {
+                Some((_, previous)) if valid => curr.partial_cmp(previous),
+                _ => Some(Ordering::Equal),
+            };
+
+            match (valid, ordering) {
+                (true, Some(ordering)) => {
+                    let from = self.previous_measurement.replace((index, curr.clone()));
+
+                    if let Some(from) = from {
+                        return Some(Step {
+                            from,
+                            to: (index, curr),
+                            ordering,
+                        });
+                    }
+                }
+                _ => {
+                    self.incomparable.push(index);
+
+                    match self.policy {
+                        Incomparable::Skip => {}
+                        Incomparable::Gap if valid => {
+                            self.previous_measurement = Some((index, curr));
+                        }
+                        Incomparable::Gap => self.previous_measurement = None,
+                        Incomparable::Error => {
+                            let err = Error::new(ErrorKind::Incomparable, "").at_line(index + 1);
+
+                            self.error = Some(err);
+                        }
+                    }
+                }
+            }
         }
+
+        None
     }
 }
 
@@ -20,15 +102,201 @@ impl<T: PartialOrd + Clone, I: Iterator<Item = T>> Iterator for OrderingIter<T,
     type Item = Ordering;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.measurements.next().and_then(|curr| {
-            if let Some(previous_measurement) = self.previous_measurement.clone() {
-                self.previous_measurement = Some(curr.clone());
+        self.next_step().map(|step| step.ordering)
+    }
+}
 
-                curr.partial_cmp(&previous_measurement)
-            } else {
-                None
+struct Step<T> {
+    from: (usize, T),
+    to: (usize, T),
+    ordering: Ordering,
+}
+
+/// A measurement that can be averaged.
+pub trait Measurement {
+    fn as_f64(&self) -> f64;
+}
+
+macro_rules! impl_measurement {
+    ($($t:ty),*) => {
+        $(impl Measurement for $t {
+            fn as_f64(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+impl_measurement!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: Measurement> Measurement for &T {
+    fn as_f64(&self) -> f64 {
+        (*self).as_f64()
+    }
+}
+
+/// Consecutive steps in the same direction, starting at measurement `start`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Run {
+    pub ordering: Ordering,
+    pub start: usize,
+    pub len: usize,
+}
+
+/// Summary of the shape of a series of measurements.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Profile {
+    counts: [usize; 3],
+    pub longest_increase: Option<Run>,
+    pub longest_decrease: Option<Run>,
+    /// Runs of measurements that stay the same.
+    pub plateaus: Vec<Run>,
+    /// The measurements at which the series stops rising and starts falling
+    /// or the other way round, ignoring any plateaus in between.
+    pub turning_points: Vec<usize>,
+    /// The average change from one measurement to the next.
+    pub mean_step: Option<f64>,
+    /// The measurements left out because they could not be compared.
+    pub incomparable: Vec<usize>,
+}
+
+/// Builds a `Profile` one step at a time.
+#[derive(Default)]
+struct Builder {
+    profile: Profile,
+    run: Option<Run>,
+    last_move: Option<(Ordering, usize)>,
+    end: Option<usize>,
+    total_step: f64,
+}
+
+impl Builder {
+    fn push(&mut self, ordering: Ordering, from: usize, to: usize) {
+        let profile = &mut self.profile;
+        let after_gap = self.end.map(|end| end != from).unwrap_or(false);
+
+        profile.counts[Profile::slot(ordering)] += 1;
+        self.end = Some(to);
+        if after_gap {
+            self.last_move = None;
+        }
+
+        if ordering != Ordering::Equal {
+            match self.last_move {
+                Some((previous, end)) if previous != ordering => profile.turning_points.push(end),
+                _ => {}
+            }
+
+            self.last_move = Some((ordering, to));
+        }
+
+        match &mut self.run {
+            Some(run) if run.ordering == ordering && !after_gap => run.len += 1,
+            run => {
+                if let Some(run) = run.take() {
+                    profile.close(run);
+                }
+
+                *run = Some(Run {
+                    ordering,
+                    start: from,
+                    len: 1,
+                });
+            }
+        }
+    }
+
+    fn finish(mut self) -> Profile {
+        if let Some(run) = self.run.take() {
+            self.profile.close(run);
+        }
+
+        self.profile
+    }
+}
+
+impl Profile {
+    /// Profiles the measurements, skipping any that cannot be compared.
+    pub fn new<T: PartialOrd + Clone + Measurement>(
+        measurements: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let mut orderings = OrderingIter::with_policy(measurements.into_iter(), Incomparable::Skip);
+
+        Self::build(&mut orderings)
+    }
+
+    pub fn with_policy<T: PartialOrd + Clone + Measurement>(
+        measurements: impl IntoIterator<Item = T>,
+        policy: Incomparable,
+    ) -> Result<Self> {
+        let mut orderings = OrderingIter::with_policy(measurements.into_iter(), policy);
+        let profile = Self::build(&mut orderings);
+
+        match orderings.error() {
+            Some(err) => Err(err.clone()),
+            None => Ok(profile),
+        }
+    }
+
+    fn build<T: PartialOrd + Clone + Measurement, I: Iterator<Item = T>>(
+        orderings: &mut OrderingIter<T, I>,
+    ) -> Self {
+        let mut builder = Builder::default();
+
+        while let Some(step) = orderings.next_step() {
+            builder.total_step += step.to.1.as_f64() - step.from.1.as_f64();
+            builder.push(step.ordering, step.from.0, step.to.0);
+        }
+
+        let total_step = builder.total_step;
+        let mut profile = builder.finish();
+        let steps = profile.steps();
+
+        if steps > 0 {
+            profile.mean_step = Some(total_step / steps as f64);
+        }
+
+        profile.incomparable = orderings.incomparable().to_vec();
+        profile
+    }
+
+    /// Everything but the mean step, which needs the measurements themselves.
+    pub fn from_orderings(orderings: impl IntoIterator<Item = Ordering>) -> Self {
+        let mut builder = Builder::default();
+
+        for (i, ordering) in orderings.into_iter().enumerate() {
+            builder.push(ordering, i, i + 1);
+        }
+
+        builder.finish()
+    }
+
+    fn slot(ordering: Ordering) -> usize {
+        (ordering as i8 + 1) as usize
+    }
+
+    fn close(&mut self, run: Run) {
+        let longest = match run.ordering {
+            Ordering::Greater => &mut self.longest_increase,
+            Ordering::Less => &mut self.longest_decrease,
+            Ordering::Equal => {
+                self.plateaus.push(run);
+                return;
             }
-        })
+        };
+
+        if longest.map(|longest| run.len > longest.len).unwrap_or(true) {
+            *longest = Some(run);
+        }
+    }
+
+    /// How many steps went in the given direction.
+    pub fn count(&self, ordering: Ordering) -> usize {
+        self.counts[Self::slot(ordering)]
+    }
+
+    pub fn steps(&self) -> usize {
+        self.counts.iter().sum()
     }
 }
 
@@ -68,4 +336,109 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn _01_profile() {
+        let sonar_sweep: [isize; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let profile = Profile::new(sonar_sweep.iter());
+
+        assert_eq!(profile.count(Ordering::Greater), 7);
+        assert_eq!(profile.count(Ordering::Less), 2);
+        assert_eq!(profile.count(Ordering::Equal), 0);
+        assert_eq!(
+            profile.longest_increase,
+            Some(Run {
+                ordering: Ordering::Greater,
+                start: 0,
+                len: 3
+            })
+        );
+        assert_eq!(profile.longest_decrease.map(|run| run.start), Some(3));
+        assert_eq!(profile.turning_points, vec![3, 4, 7, 8]);
+        assert_eq!(profile.mean_step, Some(64.0 / 9.0));
+    }
+
+    #[test]
+    fn plateaus() {
+        let profile = Profile::new([1, 2, 2, 2, 1, 1, 3]);
+
+        assert_eq!(
+            profile.plateaus,
+            vec![
+                Run {
+                    ordering: Ordering::Equal,
+                    start: 1,
+                    len: 2
+                },
+                Run {
+                    ordering: Ordering::Equal,
+                    start: 4,
+                    len: 1
+                }
+            ]
+        );
+        assert_eq!(profile.turning_points, vec![1, 4]);
+        assert_eq!(profile.mean_step, Some(2.0 / 6.0));
+        assert_eq!(Profile::new([5]).mean_step, None);
+        assert_eq!(Profile::new(Vec::<f64>::new()), Profile::default());
+    }
+
+    #[test]
+    fn incomparable() {
+        let readings = [1.0, 2.0, f64::NAN, 1.0, 3.0];
+        let orderings = |policy| {
+            let mut iter = OrderingIter::with_policy(readings.iter(), policy);
+            let orderings = iter.by_ref().collect::<Vec<_>>();
+
+            (
+                orderings,
+                iter.incomparable().to_vec(),
+                iter.error().cloned(),
+            )
+        };
+
+        assert_eq!(
+            orderings(Incomparable::Skip),
+            (
+                vec![Ordering::Greater, Ordering::Less, Ordering::Greater],
+                vec![2],
+                None
+            )
+        );
+        assert_eq!(
+            orderings(Incomparable::Gap),
+            (vec![Ordering::Greater, Ordering::Greater], vec![2], None)
+        );
+        assert_eq!(
+            orderings(Incomparable::Error),
+            (
+                vec![Ordering::Greater],
+                vec![2],
+                Some(Error::new(ErrorKind::Incomparable, "").at_line(3))
+            )
+        );
+        assert_eq!(
+            OrderingIter::with_policy([f64::NAN, 1.0, 2.0].iter(), Incomparable::Gap)
+                .collect::<Vec<_>>(),
+            vec![Ordering::Greater]
+        );
+    }
+
+    #[test]
+    fn incomparable_profile() {
+        let readings = [1.0, 2.0, 3.0, f64::NAN, 4.0, 5.0];
+        let skipped = Profile::new(readings);
+        let gapped = Profile::with_policy(readings, Incomparable::Gap).expect("no profile");
+
+        assert_eq!(skipped.longest_increase.map(|run| run.len), Some(4));
+        assert_eq!(skipped.mean_step, Some(1.0));
+        assert_eq!(skipped.incomparable, vec![3]);
+        assert_eq!(gapped.longest_increase.map(|run| run.len), Some(2));
+        assert_eq!(gapped.steps(), 3);
+        assert_eq!(gapped.mean_step, Some(1.0));
+        assert_eq!(
+            Profile::with_policy(readings, Incomparable::Error).map_err(|e| e.line()),
+            Err(Some(4))
+        );
+    }
 }
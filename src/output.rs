@@ -0,0 +1,245 @@
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+use crate::solver::Part;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle, and how long it took to get it.
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    pub day: usize,
+    pub part: Part,
+    pub answer: Result<String>,
+    pub elapsed: Duration,
+}
+
+impl Record {
+    /// Times `solve`, recording its answer to the given part of the puzzle.
+    pub fn time(day: usize, part: Part, solve: impl FnOnce() -> Result<String>) -> Self {
+        let start = Instant::now();
+        let answer = solve();
+
+        Self {
+            day,
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+/// Writes records one at a time, surrounded by whatever the format needs
+/// before the first and after the last of them.
+pub struct Output<W: Write> {
+    format: Format,
+    out: W,
+    written: usize,
+}
+
+impl<W: Write> Output<W> {
+    pub fn new(format: Format, mut out: W) -> io::Result<Self> {
+        match format {
+            Format::Text => {}
+            Format::Json => write!(out, "[")?,
+            Format::Csv => writeln!(out, "day,part,answer,elapsed_ns,error")?,
+        }
+
+        Ok(Self {
+            format,
+            out,
+            written: 0,
+        })
+    }
+
+    pub fn write(&mut self, record: &Record) -> io::Result<()> {
+        let (answer, error) = match &record.answer {
+            Ok(answer) => (Some(answer.as_str()), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+
+        match self.format {
+            Format::Text => match (answer, error) {
+                (Some(answer), _) => writeln!(
+                    self.out,
+                    "day {:02} part {}: {}",
+                    record.day, record.part, answer
+                )?,
+                (_, error) => writeln!(
+                    self.out,
+                    "day {:02} part {}: error: {}",
+                    record.day,
+                    record.part,
+                    error.unwrap_or_default()
+                )?,
+            },
+            Format::Json => {
+                let separator = if self.written == 0 { "\n" } else { ",\n" };
+
+                write!(
+                    self.out,
+                    "{}{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"error\":{}}}",
+                    separator,
+                    record.day,
+                    record.part,
+                    answer
+                        .map(json_string)
+                        .unwrap_or_else(|| "null".to_string()),
+                    record.elapsed.as_nanos(),
+                    error
+                        .as_deref()
+                        .map(json_string)
+                        .unwrap_or_else(|| "null".to_string())
+                )?
+            }
+            Format::Csv => writeln!(
+                self.out,
+                "{},{},{},{},{}",
+                record.day,
+                record.part,
+                csv_field(answer.unwrap_or("")),
+                record.elapsed.as_nanos(),
+                csv_field(error.as_deref().unwrap_or(""))
+            )?,
+        }
+
+        self.written += 1;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.format == Format::Json {
+            let end = if self.written == 0 { "]" } else { "\n]" };
+
+            writeln!(self.out, "{}", end)?;
+        }
+
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// Quotes a string for use as a JSON value.
+pub fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+
+    for ch in s.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => quoted.push(ch),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Quotes a CSV field, but only if it has to be.
+pub fn csv_field(s: &str) -> String {
+    if s.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Error, ErrorKind};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 13,
+                part: Part::Two,
+                answer: Ok("#.\n.#\n".to_string()),
+                elapsed: Duration::from_nanos(1500),
+            },
+            Record {
+                day: 1,
+                part: Part::One,
+                answer: Err(Error::new(ErrorKind::InvalidNumber, "x").on_day(1)),
+                elapsed: Duration::from_nanos(20),
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut output = Output::new(format, vec![]).expect("not written");
+
+        for record in records() {
+            output.write(&record).expect("not written");
+        }
+
+        String::from_utf8(output.finish().expect("not written")).expect("not utf-8")
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+        assert_eq!("xml".parse::<Format>(), Err("xml".to_string()));
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            render(Format::Text),
+            "day 13 part 2: #.\n.#\n\nday 01 part 1: error: day 1: invalid number in `x`\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            render(Format::Json),
+            "[\n{\"day\":13,\"part\":2,\"answer\":\"#.\\n.#\\n\",\"elapsed_ns\":1500,\"error\":null},\n\
+             {\"day\":1,\"part\":1,\"answer\":null,\"elapsed_ns\":20,\"error\":\"day 1: invalid number in `x`\"}\n]\n"
+        );
+        assert_eq!(
+            String::from_utf8(
+                Output::new(Format::Json, vec![])
+                    .and_then(Output::finish)
+                    .expect("not written")
+            ),
+            Ok("[]\n".to_string())
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv),
+            "day,part,answer,elapsed_ns,error\n\
+             13,2,\"#.\n.#\n\",1500,\n\
+             1,1,,20,day 1: invalid number in `x`\n"
+        );
+        assert_eq!(csv_field("a \"b\", c"), "\"a \"\"b\"\", c\"");
+    }
+}
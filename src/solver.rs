@@ -0,0 +1,218 @@
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::str::FromStr;
+
+use crate::bench::{self, Timings};
+use crate::error::{Error, ErrorKind, Result};
+use crate::input::Input;
+use crate::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+    day14, day15, day16, day17, day18,
+};
+
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A solution to one day of the calendar, split into parsing the puzzle
+/// input and answering each of the two parts from the parsed input.
+pub trait Solver {
+    const DAY: usize;
+
+    type Input;
+
+    fn parse(lines: &[&str]) -> Result<Self::Input>;
+    fn part_1(input: &Self::Input) -> Option<String>;
+    fn part_2(input: &Self::Input) -> Option<String>;
+}
+
+pub fn solve<S: Solver>(lines: &[&str], part: Part) -> Result<String> {
+    let input = S::parse(lines).map_err(|e| e.on_day(S::DAY))?;
+
+    answer::<S>(&input, part)
+}
+
+/// Answers one part from input that has already been parsed.
+pub fn answer<S: Solver>(input: &S::Input, part: Part) -> Result<String> {
+    let answer = match part {
+        Part::One => S::part_1(input),
+        Part::Two => S::part_2(input),
+    };
+
+    answer.ok_or_else(|| Error::new(ErrorKind::NoSolution, "").on_day(S::DAY))
+}
+
+/// Reads the puzzle input from stdin and prints the answer to both parts,
+/// one per line.
+pub fn main<S: Solver>() -> io::Result<()> {
+    let lines = Input::stdin().lines()?;
+    let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    let input = S::parse(&lines)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.on_day(S::DAY)))?;
+
+    print_answers::<S>(&input)
+}
+
+/// Prints the answer to both parts of the parsed input, one per line.
+pub fn print_answers<S: Solver>(input: &S::Input) -> io::Result<()> {
+    for part in Part::ALL {
+        let answer =
+            answer::<S>(input, part).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        println!("{}", answer);
+    }
+
+    Ok(())
+}
+
+pub struct Puzzle {
+    pub day: usize,
+    pub solve: fn(&[&str], Part) -> Result<String>,
+    pub bench: fn(&[&str], &[Part], usize) -> Result<Timings>,
+}
+
+pub fn puzzle(day: usize) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.day == day)
+}
+
+pub static PUZZLES: [Puzzle; 18] = [
+    Puzzle {
+        day: day01::Day01::DAY,
+        solve: solve::<day01::Day01>,
+        bench: bench::bench::<day01::Day01>,
+    },
+    Puzzle {
+        day: day02::Day02::DAY,
+        solve: solve::<day02::Day02>,
+        bench: bench::bench::<day02::Day02>,
+    },
+    Puzzle {
+        day: day03::Day03::DAY,
+        solve: solve::<day03::Day03>,
+        bench: bench::bench::<day03::Day03>,
+    },
+    Puzzle {
+        day: day04::Day04::DAY,
+        solve: solve::<day04::Day04>,
+        bench: bench::bench::<day04::Day04>,
+    },
+    Puzzle {
+        day: day05::Day05::DAY,
+        solve: solve::<day05::Day05>,
+        bench: bench::bench::<day05::Day05>,
+    },
+    Puzzle {
+        day: day06::Day06::DAY,
+        solve: solve::<day06::Day06>,
+        bench: bench::bench::<day06::Day06>,
+    },
+    Puzzle {
+        day: day07::Day07::DAY,
+        solve: solve::<day07::Day07>,
+        bench: bench::bench::<day07::Day07>,
+    },
+    Puzzle {
+        day: day08::Day08::DAY,
+        solve: solve::<day08::Day08>,
+        bench: bench::bench::<day08::Day08>,
+    },
+    Puzzle {
+        day: day09::Day09::DAY,
+        solve: solve::<day09::Day09>,
+        bench: bench::bench::<day09::Day09>,
+    },
+    Puzzle {
+        day: day10::Day10::DAY,
+        solve: solve::<day10::Day10>,
+        bench: bench::bench::<day10::Day10>,
+    },
+    Puzzle {
+        day: day11::Day11::DAY,
+        solve: solve::<day11::Day11>,
+        bench: bench::bench::<day11::Day11>,
+    },
+    Puzzle {
+        day: day12::Day12::DAY,
+        solve: solve::<day12::Day12>,
+        bench: bench::bench::<day12::Day12>,
+    },
+    Puzzle {
+        day: day13::Day13::DAY,
+        solve: solve::<day13::Day13>,
+        bench: bench::bench::<day13::Day13>,
+    },
+    Puzzle {
+        day: day14::Day14::DAY,
+        solve: solve::<day14::Day14>,
+        bench: bench::bench::<day14::Day14>,
+    },
+    Puzzle {
+        day: day15::Day15::DAY,
+        solve: solve::<day15::Day15>,
+        bench: bench::bench::<day15::Day15>,
+    },
+    Puzzle {
+        day: day16::Day16::DAY,
+        solve: solve::<day16::Day16>,
+        bench: bench::bench::<day16::Day16>,
+    },
+    Puzzle {
+        day: day17::Day17::DAY,
+        solve: solve::<day17::Day17>,
+        bench: bench::bench::<day17::Day17>,
+    },
+    Puzzle {
+        day: day18::Day18::DAY,
+        solve: solve::<day18::Day18>,
+        bench: bench::bench::<day18::Day18>,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse::<Part>(), Ok(Part::One));
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn all_puzzles() {
+        assert_eq!(
+            PUZZLES.iter().map(|p| p.day).collect::<Vec<_>>(),
+            (1..=18).collect::<Vec<_>>()
+        );
+        assert!(puzzle(18).is_some());
+        assert!(puzzle(19).is_none());
+    }
+}
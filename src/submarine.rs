@@ -1,7 +1,52 @@
-pub trait Submarine<S> {
-    fn forward(&mut self, amount: isize) -> &mut S;
-    fn up(&mut self, amount: isize) -> &mut S;
-    fn down(&mut self, amount: isize) -> &mut S;
+use crate::submarine_command::Command;
+
+/// Where a submarine is and which way it is heading.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Position {
+    pub horizontal: isize,
+    pub depth: isize,
+    pub aim: isize,
+}
+
+/// How a submarine responds to each command. Models that only change how
+/// another one behaves wrap it, like `DepthLimit`, `Fuel` and `Drift`.
+pub trait Submarine {
+    fn forward(&mut self, amount: isize) -> &mut Self;
+    fn up(&mut self, amount: isize) -> &mut Self;
+    fn down(&mut self, amount: isize) -> &mut Self;
+    fn position(&self) -> Position;
+    fn set_position(&mut self, position: Position);
+
+    fn horizontal(&self) -> isize {
+        self.position().horizontal
+    }
+
+    fn depth(&self) -> isize {
+        self.position().depth
+    }
+
+    fn aim(&self) -> isize {
+        self.position().aim
+    }
+
+    fn product(&self) -> isize {
+        self.horizontal() * self.depth()
+    }
+
+    fn apply(&mut self, command: &Command) -> &mut Self {
+        match *command {
+            Command::Forward(amount) => self.forward(amount),
+            Command::Back(amount) => self.forward(-amount),
+            Command::Down(amount) => self.down(amount),
+            Command::Up(amount) => self.up(amount),
+        }
+    }
+}
+
+/// Applies `command` to a submarine taken by value, to fold a course over.
+pub fn execute<S: Submarine>(mut sub: S, command: &Command) -> S {
+    sub.apply(command);
+    sub
 }
 
 #[derive(Debug, PartialEq)]
@@ -16,7 +61,7 @@ impl Default for SubmarineV1 {
     }
 }
 
-impl Submarine<Self> for SubmarineV1 {
+impl Submarine for SubmarineV1 {
     fn forward(&mut self, amount: isize) -> &mut Self {
         self.horizontal += amount;
         self
@@ -31,16 +76,25 @@ impl Submarine<Self> for SubmarineV1 {
         self.depth += amount;
         self
     }
+
+    fn position(&self) -> Position {
+        Position {
+            horizontal: self.horizontal,
+            depth: self.depth,
+            aim: 0,
+        }
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.horizontal = position.horizontal;
+        self.depth = position.depth;
+    }
 }
 
 impl SubmarineV1 {
     pub fn new(horizontal: isize, depth: isize) -> Self {
         Self { horizontal, depth }
     }
-
-    pub fn product(&self) -> isize {
-        self.horizontal * self.depth
-    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -56,7 +110,7 @@ impl Default for SubmarineV2 {
     }
 }
 
-impl Submarine<SubmarineV2> for SubmarineV2 {
+impl Submarine for SubmarineV2 {
     fn forward(&mut self, amount: isize) -> &mut Self {
         self.horizontal += amount;
         self.depth += self.aim * amount;
@@ -72,6 +126,20 @@ impl Submarine<SubmarineV2> for SubmarineV2 {
         self.aim += amount;
         self
     }
+
+    fn position(&self) -> Position {
+        Position {
+            horizontal: self.horizontal,
+            depth: self.depth,
+            aim: self.aim,
+        }
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.horizontal = position.horizontal;
+        self.depth = position.depth;
+        self.aim = position.aim;
+    }
 }
 
 impl SubmarineV2 {
@@ -82,9 +150,168 @@ impl SubmarineV2 {
             aim,
         }
     }
+}
+
+/// Keeps another submarine between the surface and `max_depth`, surfacing
+/// it instead of letting it fly.
+#[derive(Debug, PartialEq)]
+pub struct DepthLimit<S> {
+    inner: S,
+    max_depth: isize,
+}
+
+impl<S: Submarine> DepthLimit<S> {
+    pub fn new(inner: S, max_depth: isize) -> Self {
+        Self { inner, max_depth }
+    }
 
-    pub fn product(&self) -> isize {
-        self.horizontal * self.depth
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn clamp(&mut self) -> &mut Self {
+        let mut position = self.inner.position();
+
+        position.depth = position.depth.clamp(0, self.max_depth.max(0));
+        self.inner.set_position(position);
+        self
+    }
+}
+
+impl<S: Submarine> Submarine for DepthLimit<S> {
+    fn forward(&mut self, amount: isize) -> &mut Self {
+        self.inner.forward(amount);
+        self.clamp()
+    }
+
+    fn up(&mut self, amount: isize) -> &mut Self {
+        self.inner.up(amount);
+        self.clamp()
+    }
+
+    fn down(&mut self, amount: isize) -> &mut Self {
+        self.inner.down(amount);
+        self.clamp()
+    }
+
+    fn position(&self) -> Position {
+        self.inner.position()
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.inner.set_position(position);
+        self.clamp();
+    }
+}
+
+/// Burns one unit of fuel for every unit of every command, carrying out as
+/// much of a command as the fuel that is left allows.
+#[derive(Debug, PartialEq)]
+pub struct Fuel<S> {
+    inner: S,
+    fuel: isize,
+}
+
+impl<S: Submarine> Fuel<S> {
+    pub fn new(inner: S, fuel: isize) -> Self {
+        Self { inner, fuel }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    pub fn fuel(&self) -> isize {
+        self.fuel
+    }
+
+    fn burn(&mut self, amount: isize) -> isize {
+        let burnt = amount.unsigned_abs().min(self.fuel.max(0) as usize) as isize;
+
+        self.fuel -= burnt;
+        burnt * amount.signum()
+    }
+}
+
+impl<S: Submarine> Submarine for Fuel<S> {
+    fn forward(&mut self, amount: isize) -> &mut Self {
+        let amount = self.burn(amount);
+
+        self.inner.forward(amount);
+        self
+    }
+
+    fn up(&mut self, amount: isize) -> &mut Self {
+        let amount = self.burn(amount);
+
+        self.inner.up(amount);
+        self
+    }
+
+    fn down(&mut self, amount: isize) -> &mut Self {
+        let amount = self.burn(amount);
+
+        self.inner.down(amount);
+        self
+    }
+
+    fn position(&self) -> Position {
+        self.inner.position()
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.inner.set_position(position);
+    }
+}
+
+/// A current that moves another submarine `drift` further along after
+/// every command.
+#[derive(Debug, PartialEq)]
+pub struct Drift<S> {
+    inner: S,
+    drift: isize,
+}
+
+impl<S: Submarine> Drift<S> {
+    pub fn new(inner: S, drift: isize) -> Self {
+        Self { inner, drift }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn drift(&mut self) -> &mut Self {
+        let mut position = self.inner.position();
+
+        position.horizontal += self.drift;
+        self.inner.set_position(position);
+        self
+    }
+}
+
+impl<S: Submarine> Submarine for Drift<S> {
+    fn forward(&mut self, amount: isize) -> &mut Self {
+        self.inner.forward(amount);
+        self.drift()
+    }
+
+    fn up(&mut self, amount: isize) -> &mut Self {
+        self.inner.up(amount);
+        self.drift()
+    }
+
+    fn down(&mut self, amount: isize) -> &mut Self {
+        self.inner.down(amount);
+        self.drift()
+    }
+
+    fn position(&self) -> Position {
+        self.inner.position()
+    }
+
+    fn set_position(&mut self, position: Position) {
+        self.inner.set_position(position);
     }
 }
 
@@ -115,4 +342,63 @@ mod tests {
         assert_eq!(*submarine.down(8), SubmarineV2::new(13, 40, 10));
         assert_eq!(*submarine.forward(2), SubmarineV2::new(15, 60, 10));
     }
+
+    fn planned_course() -> Vec<Command> {
+        vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ]
+    }
+
+    fn follow<S: Submarine>(mut submarine: S) -> S {
+        for command in &planned_course() {
+            submarine.apply(command);
+        }
+
+        submarine
+    }
+
+    #[test]
+    fn apply() {
+        assert_eq!(follow(SubmarineV1::default()).product(), 150);
+        assert_eq!(follow(SubmarineV2::default()).aim(), 10);
+        assert_eq!(
+            SubmarineV1::default().apply(&Command::Back(3)).horizontal(),
+            -3
+        );
+    }
+
+    #[test]
+    fn depth_limit() {
+        let submarine = follow(DepthLimit::new(SubmarineV2::default(), 50));
+        assert_eq!(
+            submarine.position(),
+            SubmarineV2::new(15, 50, 10).position()
+        );
+
+        let mut submarine = DepthLimit::new(SubmarineV1::default(), 50);
+        assert_eq!(submarine.up(4).depth(), 0);
+    }
+
+    #[test]
+    fn fuel() {
+        let submarine = follow(Fuel::new(SubmarineV1::default(), 20));
+
+        assert_eq!(submarine.fuel(), 0);
+        assert_eq!(submarine.into_inner(), SubmarineV1::new(13, 3));
+
+        let mut submarine = Fuel::new(SubmarineV1::default(), 5);
+        assert_eq!(submarine.forward(isize::MIN).horizontal(), -5);
+    }
+
+    #[test]
+    fn drift() {
+        let submarine = follow(Drift::new(SubmarineV1::default(), 1));
+
+        assert_eq!(submarine.into_inner(), SubmarineV1::new(21, 10));
+    }
 }
@@ -1,42 +1,251 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
-#[derive(Clone, Debug, PartialEq)]
-pub enum CommandParseErr {
-    TooFewTokens(String),
-    MissingUnits(String),
-    UnrecognizedCommand(String),
-}
+use crate::error::{parse_number, Error, ErrorKind, Result};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Command {
     Forward(isize),
+    Back(isize),
     Down(isize),
     Up(isize),
 }
 
 impl FromStr for Command {
-    type Err = CommandParseErr;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.trim().split(' ').collect::<Vec<_>>();
-
-        if parts.len() != 2 {
-            Err(CommandParseErr::TooFewTokens(s.to_string()))
-        } else {
-            let amount = parts[1]
-                .parse::<isize>()
-                .map_err(|_| CommandParseErr::MissingUnits(parts[1].to_string()))?;
-
-            match parts[0] {
-                "forward" => Ok(Command::Forward(amount)),
-                "down" => Ok(Command::Down(amount)),
-                "up" => Ok(Command::Up(amount)),
-                _ => Err(CommandParseErr::UnrecognizedCommand(parts[0].to_string())),
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+
+        parse_command(s, &tokens, &HashMap::new())
+    }
+}
+
+const VERBS: [&str; 4] = ["forward", "back", "down", "up"];
+
+/// The most commands a script may expand to once its blocks are repeated.
+pub const MAX_COMMANDS: usize = 1_000_000;
+
+/// Other names for verbs, by their lowercase name.
+type Aliases = HashMap<String, &'static str>;
+
+fn resolve(word: &str, aliases: &Aliases) -> Option<&'static str> {
+    let word = word.to_ascii_lowercase();
+
+    VERBS
+        .iter()
+        .copied()
+        .find(|&verb| verb == word)
+        .or_else(|| aliases.get(&word).copied())
+}
+
+fn parse_command(s: &str, tokens: &[&str], aliases: &Aliases) -> Result<Command> {
+    let (verb, amount) = match *tokens {
+        [verb, amount] => (verb, amount),
+        [_, _, extra, ..] => return Err(Error::new(ErrorKind::TooManyTokens, s).within(s, extra)),
+        _ => return Err(Error::new(ErrorKind::TooFewTokens, s)),
+    };
+
+    // Every amount must be negatable, since `back` moves by the negation.
+    let amount = parse_number::<isize>(amount)
+        .and_then(|n| {
+            Some(n).filter(|&n| n != isize::MIN).ok_or_else(|| {
+                Error::new(ErrorKind::Malformed("a smaller amount"), amount).within(amount, amount)
+            })
+        })
+        .map_err(|e| e.within(s, amount))?;
+
+    match resolve(verb, aliases) {
+        Some("forward") => Ok(Command::Forward(amount)),
+        Some("back") => Ok(Command::Back(amount)),
+        Some("down") => Ok(Command::Down(amount)),
+        Some("up") => Ok(Command::Up(amount)),
+        _ => Err(Error::new(ErrorKind::UnrecognizedToken, verb).within(s, verb)),
+    }
+}
+
+/// Parses a course script: one command per line, with `#` starting a
+/// comment, blank lines ignored, other names given to verbs with
+/// `alias <name> <verb>` and blocks of commands repeated with
+///
+/// ```text
+/// repeat 3 {
+///     forward 5
+///     down 2
+/// }
+/// ```
+///
+/// A script may expand to at most `MAX_COMMANDS` commands.
+pub fn parse_script(lines: &[&str]) -> Result<Vec<Command>> {
+    let mut parser = Parser {
+        lines,
+        next: 0,
+        aliases: HashMap::new(),
+    };
+    let commands = parser.parse_block()?;
+
+    match lines.get(parser.next) {
+        Some(line) => Err(unexpected_close(line).at_line(parser.next + 1)),
+        None => Ok(commands),
+    }
+}
+
+struct Parser<'a> {
+    lines: &'a [&'a str],
+    next: usize,
+    aliases: Aliases,
+}
+
+impl Parser<'_> {
+    /// Parses lines up to the end of the script or the `}` closing the
+    /// block, leaving `next` pointing at the latter.
+    fn parse_block(&mut self) -> Result<Vec<Command>> {
+        let mut commands = vec![];
+
+        while let Some(&line) = self.lines.get(self.next) {
+            let i = self.next;
+            let code = line.split('#').next().unwrap_or("");
+            let tokens = tokenize(code);
+
+            match tokens[..] {
+                [] => {}
+                ["}"] => return Ok(commands),
+                ["}", extra, ..] => {
+                    return Err(Error::new(ErrorKind::TooManyTokens, code)
+                        .within(code, extra)
+                        .at_line(i + 1))
+                }
+                [first, ..] if first.eq_ignore_ascii_case("repeat") => {
+                    let times = parse_repeat(code, &tokens).map_err(|e| e.at_line(i + 1))?;
+
+                    self.next += 1;
+                    let block = self.parse_block()?;
+
+                    if self.next == self.lines.len() {
+                        return Err(Error::new(ErrorKind::MissingToken("}"), line).at_line(i + 1));
+                    }
+
+                    times
+                        .checked_mul(block.len())
+                        .and_then(|n| n.checked_add(commands.len()))
+                        .filter(|&n| n <= MAX_COMMANDS)
+                        .ok_or_else(|| too_many_commands(code).at_line(i + 1))?;
+
+                    for _ in 0..times {
+                        commands.extend_from_slice(&block);
+                    }
+                }
+                [first, ..] if first.eq_ignore_ascii_case("alias") => {
+                    self.define(code, &tokens).map_err(|e| e.at_line(i + 1))?
+                }
+                _ => {
+                    if commands.len() == MAX_COMMANDS {
+                        return Err(too_many_commands(code).at_line(i + 1));
+                    }
+
+                    let command = parse_command(code, &tokens, &self.aliases)
+                        .map_err(|e| e.at_line(i + 1))?;
+                    commands.push(command);
+                }
+            }
+
+            self.next += 1;
+        }
+
+        Ok(commands)
+    }
+
+    /// Parses `alias <name> <verb>`, after which `name` can be used in place
+    /// of `verb`.
+    fn define(&mut self, code: &str, tokens: &[&str]) -> Result<()> {
+        let (name, target) = match *tokens {
+            [_, name, target] => (name, target),
+            [_, _, _, extra, ..] => {
+                return Err(Error::new(ErrorKind::TooManyTokens, code).within(code, extra))
+            }
+            _ => return Err(Error::new(ErrorKind::TooFewTokens, code)),
+        };
+
+        let verb = resolve(target, &self.aliases)
+            .ok_or_else(|| Error::new(ErrorKind::UnrecognizedToken, target).within(code, target))?;
+        let name_taken = VERBS.iter().any(|v| v.eq_ignore_ascii_case(name))
+            || ["repeat", "alias", "{", "}"]
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(name));
+
+        if name_taken {
+            return Err(
+                Error::new(ErrorKind::Malformed("a name that is not a verb"), name)
+                    .within(code, name),
+            );
+        }
+
+        self.aliases.insert(name.to_ascii_lowercase(), verb);
+        Ok(())
+    }
+}
+
+/// Splits a line into words, with every `{` and `}` a word of its own.
+fn tokenize(code: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut start = None;
+
+    for (i, ch) in code.char_indices() {
+        if ch.is_whitespace() || ch == '{' || ch == '}' {
+            if let Some(s) = start.take() {
+                tokens.push(&code[s..i]);
             }
+
+            if !ch.is_whitespace() {
+                tokens.push(&code[i..i + 1]);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+
+    if let Some(s) = start {
+        tokens.push(&code[s..]);
+    }
+
+    tokens
+}
+
+/// Parses `repeat <times> {`, returning the number of times.
+fn parse_repeat(code: &str, tokens: &[&str]) -> Result<usize> {
+    match *tokens {
+        [_] => Err(Error::new(ErrorKind::TooFewTokens, code)),
+        [_, times] => {
+            parse_number::<usize>(times).map_err(|e| e.within(code, times))?;
+
+            Err(Error::new(ErrorKind::MissingToken("{"), code))
         }
+        [_, times, "{"] => parse_number::<usize>(times).map_err(|e| e.within(code, times)),
+        [_, _, other] => Err(
+            Error::new(ErrorKind::UnexpectedCharacter(first_char(other)), code).within(code, other),
+        ),
+        [_, _, _, extra, ..] => Err(Error::new(ErrorKind::TooManyTokens, code).within(code, extra)),
+        [] => unreachable!("blank lines are skipped"),
     }
 }
 
+fn too_many_commands(code: &str) -> Error {
+    Error::new(
+        ErrorKind::LimitExceeded("commands", MAX_COMMANDS),
+        code.trim(),
+    )
+}
+
+fn unexpected_close(line: &str) -> Error {
+    let brace = line.find('}').unwrap_or(0);
+
+    Error::new(ErrorKind::UnexpectedCharacter('}'), line).at_column(brace + 1)
+}
+
+fn first_char(s: &str) -> char {
+    s.chars().next().unwrap_or(' ')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,4 +276,152 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn _01_parse_errors() {
+        let err = "forward x".parse::<Command>().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidNumber);
+        assert_eq!(err.column(), Some(9));
+
+        let err = "sideways 5".parse::<Command>().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnrecognizedToken);
+        assert_eq!(err.text(), "sideways");
+        assert_eq!(err.column(), Some(1));
+
+        let err = "forward".parse::<Command>().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::TooFewTokens);
+
+        let err = "forward -9223372036854775808"
+            .parse::<Command>()
+            .unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Malformed("a smaller amount"));
+        assert_eq!(err.column(), Some(9));
+        assert_eq!(
+            "back -9223372036854775807".parse::<Command>(),
+            Ok(Command::Back(-isize::MAX))
+        );
+    }
+
+    #[test]
+    fn parse_tokens() {
+        assert_eq!("  Forward   5 ".parse::<Command>(), Ok(Command::Forward(5)));
+        assert_eq!("BACK 2".parse::<Command>(), Ok(Command::Back(2)));
+
+        let err = "up 3 4".parse::<Command>().unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::TooManyTokens);
+        assert_eq!(err.column(), Some(6));
+    }
+
+    #[test]
+    fn script() {
+        let script = [
+            "# a comment",
+            "down 2",
+            "",
+            "repeat 2 { # twice",
+            "    forward 1",
+            "    REPEAT 2 {",
+            "        up 1",
+            "    }",
+            "}",
+            "back 3 # go back",
+        ];
+
+        assert_eq!(
+            parse_script(&script),
+            Ok(vec![
+                Command::Down(2),
+                Command::Forward(1),
+                Command::Up(1),
+                Command::Up(1),
+                Command::Forward(1),
+                Command::Up(1),
+                Command::Up(1),
+                Command::Back(3)
+            ])
+        );
+        assert_eq!(parse_script(&["repeat 0 {", "}"]), Ok(vec![]));
+        assert_eq!(
+            parse_script(&["repeat 2{", "forward 1", "}# done"]),
+            Ok(vec![Command::Forward(1), Command::Forward(1)])
+        );
+    }
+
+    #[test]
+    fn aliases() {
+        let script = [
+            "alias ahead forward",
+            "alias Dive down",
+            "alias plunge dive",
+            "AHEAD 2",
+            "plunge 3",
+        ];
+
+        assert_eq!(
+            parse_script(&script),
+            Ok(vec![Command::Forward(2), Command::Down(3)])
+        );
+
+        let err = parse_script(&["alias ahead sideways"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnrecognizedToken);
+        assert_eq!(err.column(), Some(13));
+
+        let err = parse_script(&["alias up down"]).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::Malformed("a name that is not a verb")
+        );
+
+        let err = parse_script(&["ahead 1", "alias ahead forward"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnrecognizedToken);
+        assert_eq!(err.line(), Some(1));
+    }
+
+    #[test]
+    fn limit() {
+        let script = [
+            "down 1",
+            "repeat 1000000000 {",
+            "    repeat 1000000000 {",
+            "        forward 1",
+            "    }",
+            "}",
+        ];
+
+        let err = parse_script(&script).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &ErrorKind::LimitExceeded("commands", MAX_COMMANDS)
+        );
+        assert_eq!(err.line(), Some(3));
+
+        let err = parse_script(&["down 1", "repeat 1000000 {", "up 1", "}"]).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(
+            parse_script(&["repeat 1000000 {", "up 1", "}"]).map(|c| c.len()),
+            Ok(MAX_COMMANDS)
+        );
+    }
+
+    #[test]
+    fn script_errors() {
+        let err = parse_script(&["down 1", "repeat 2 {", "up 1"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken("}"));
+        assert_eq!(err.line(), Some(2));
+
+        let err = parse_script(&["down 1", "  }"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnexpectedCharacter('}'));
+        assert_eq!((err.line(), err.column()), (Some(2), Some(3)));
+
+        let err = parse_script(&["repeat x {"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::InvalidNumber);
+        assert_eq!((err.line(), err.column()), (Some(1), Some(8)));
+
+        let err = parse_script(&["repeat 2"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MissingToken("{"));
+
+        let err = parse_script(&["repeat 2 {", "sideways 1", "}"]).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::UnrecognizedToken);
+        assert_eq!((err.line(), err.column()), (Some(2), Some(1)));
+    }
 }
@@ -0,0 +1,168 @@
+use crate::submarine::{execute, Position, Submarine};
+use crate::submarine_command::Command;
+
+/// The position of a submarine after `step` commands.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub step: usize,
+    pub position: Position,
+}
+
+/// Every position a submarine passed through while following a course,
+/// starting with the one it was in before the first command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trajectory {
+    samples: Vec<Sample>,
+}
+
+impl Trajectory {
+    pub const CSV_HEADER: &'static str = "step,horizontal,depth,aim";
+
+    /// Follows the course, returning the submarine at the end of it together
+    /// with the way it got there.
+    pub fn record<S: Submarine>(sub: S, course: &[Command]) -> (S, Self) {
+        let mut samples = Vec::with_capacity(course.len() + 1);
+        samples.push(Sample {
+            step: 0,
+            position: sub.position(),
+        });
+
+        let sub = course.iter().enumerate().fold(sub, |sub, (i, command)| {
+            let sub = execute(sub, command);
+
+            samples.push(Sample {
+                step: i + 1,
+                position: sub.position(),
+            });
+            sub
+        });
+
+        (sub, Self { samples })
+    }
+
+    pub fn samples(&self) -> &[Sample] {
+        &self.samples
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// The first sample at the deepest point of the course.
+    pub fn max_depth(&self) -> Option<&Sample> {
+        self.samples
+            .iter()
+            .rev()
+            .max_by_key(|sample| sample.position.depth)
+    }
+
+    /// `CSV_HEADER` followed by one line per sample.
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", Self::CSV_HEADER);
+
+        for sample in &self.samples {
+            let Position {
+                horizontal,
+                depth,
+                aim,
+            } = sample.position;
+
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                sample.step, horizontal, depth, aim
+            ));
+        }
+
+        csv
+    }
+
+    /// Draws the course as a line of depth against horizontal position, with
+    /// depth increasing downwards.
+    pub fn to_svg(&self) -> String {
+        let xs = self.samples.iter().map(|s| s.position.horizontal);
+        let ys = self.samples.iter().map(|s| s.position.depth);
+        let (min_x, max_x) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+        let (min_y, max_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+        let points = self
+            .samples
+            .iter()
+            .map(|s| format!("{},{}", s.position.horizontal, s.position.depth))
+            .collect::<Vec<_>>();
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" preserveAspectRatio=\"none\">\n\
+             <polyline fill=\"none\" stroke=\"black\" vector-effect=\"non-scaling-stroke\" points=\"{}\"/>\n\
+             </svg>\n",
+            min_x,
+            min_y,
+            (max_x - min_x).max(1),
+            (max_y - min_y).max(1),
+            points.join(" ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::submarine::{SubmarineV1, SubmarineV2};
+
+    fn planned_course() -> Vec<Command> {
+        vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ]
+    }
+
+    #[test]
+    fn _02_record() {
+        let (sub, trajectory) = Trajectory::record(SubmarineV2::default(), &planned_course());
+        let last = trajectory.samples().last().expect("no samples");
+
+        assert_eq!(trajectory.len(), 7);
+        assert_eq!(sub.position(), last.position);
+        assert_eq!(
+            trajectory.samples()[3],
+            Sample {
+                step: 3,
+                position: Position {
+                    horizontal: 13,
+                    depth: 40,
+                    aim: 5
+                }
+            }
+        );
+        assert_eq!(trajectory.max_depth().map(|s| s.step), Some(6));
+    }
+
+    #[test]
+    fn max_depth() {
+        let (_, trajectory) = Trajectory::record(SubmarineV1::default(), &planned_course());
+
+        assert_eq!(trajectory.max_depth().map(|s| s.step), Some(5));
+        assert_eq!(trajectory.max_depth().map(|s| s.position.depth), Some(10));
+    }
+
+    #[test]
+    fn export() {
+        let course = [Command::Forward(2), Command::Down(3)];
+        let (_, trajectory) = Trajectory::record(SubmarineV1::default(), &course);
+
+        assert_eq!(
+            trajectory.to_csv(),
+            "step,horizontal,depth,aim\n0,0,0,0\n1,2,0,0\n2,2,3,0\n"
+        );
+        assert!(trajectory
+            .to_svg()
+            .contains("viewBox=\"0 0 2 3\" preserveAspectRatio=\"none\""));
+        assert!(trajectory.to_svg().contains("points=\"0,0 2,0 2,3\""));
+    }
+}
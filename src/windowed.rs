@@ -1,46 +1,169 @@
-pub struct Windowed<'a, T> {
-    buf: &'a [T],
-    index: usize,
+use std::collections::VecDeque;
+use std::iter::Fuse;
+
+/// Windows over the items of any iterator, kept in a ring buffer that never
+/// holds more than one window, so the input can be of any length.
+///
+/// Windows borrow the buffer, so they are read with `next_window`, or turned
+/// into an iterator of owned values with `map`.
+pub struct Windowed<I: Iterator> {
+    items: Fuse<I>,
+    buf: VecDeque<I::Item>,
     window_size: usize,
+    step: usize,
+    partial: bool,
+    started: bool,
 }
 
-impl<'a, T> Windowed<'a, T> {
-    pub fn new(buf: &'a [T], window_size: usize) -> Self {
+impl<I: Iterator> Windowed<I> {
+    /// Overlapping windows, each starting one item after the previous one.
+    pub fn new(items: impl IntoIterator<IntoIter = I>, window_size: usize) -> Self {
+        assert!(window_size > 0, "window size must be non-zero");
+
         Self {
-            buf,
-            index: 0,
+            items: items.into_iter().fuse(),
+            buf: VecDeque::with_capacity(window_size),
             window_size,
+            step: 1,
+            partial: false,
+            started: false,
         }
     }
-}
 
-impl<'a, T> Iterator for Windowed<'a, T> {
-    type Item = &'a [T];
+    /// Windows that follow on from each other without overlapping.
+    pub fn tumbling(items: impl IntoIterator<IntoIter = I>, window_size: usize) -> Self {
+        Self::new(items, window_size).step(window_size)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let upper = self.index + self.window_size;
-        let start = self.index;
+    /// Starts each window `step` items after the previous one. Steps larger
+    /// than the window size skip the items in between.
+    pub fn step(mut self, step: usize) -> Self {
+        assert!(step > 0, "step must be non-zero");
+
+        self.step = step;
+        self
+    }
+
+    /// Also yields the shorter windows left over at the end of the input.
+    pub fn partial(mut self) -> Self {
+        self.partial = true;
+        self
+    }
+
+    pub fn next_window(&mut self) -> Option<&[I::Item]> {
+        if self.started {
+            let popped = self.step.min(self.buf.len());
 
-        if upper <= self.buf.len() {
-            self.index += 1;
-            Some(&self.buf[start..upper])
+            self.buf.drain(..popped);
+            for _ in popped..self.step {
+                self.items.next()?;
+            }
+        }
+
+        self.started = true;
+        while self.buf.len() < self.window_size {
+            match self.items.next() {
+                Some(item) => self.buf.push_back(item),
+                None => break,
+            }
+        }
+
+        if self.buf.len() == self.window_size || (self.partial && !self.buf.is_empty()) {
+            Some(self.buf.make_contiguous())
         } else {
             None
         }
     }
+
+    pub fn map<U, F: FnMut(&[I::Item]) -> U>(self, f: F) -> Map<I, F> {
+        Map { windows: self, f }
+    }
+}
+
+pub struct Map<I: Iterator, F> {
+    windows: Windowed<I>,
+    f: F,
+}
+
+impl<I: Iterator, U, F: FnMut(&[I::Item]) -> U> Iterator for Map<I, F> {
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.windows.next_window()?;
+
+        Some((self.f)(window))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn windows<I: Iterator<Item = usize>>(windowed: Windowed<I>) -> Vec<Vec<usize>> {
+        windowed.map(|w| w.to_vec()).collect()
+    }
+
     #[test]
     fn _01_example() {
         let sonar_sweep: [isize; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
-        let windowed_sum = Windowed::new(&sonar_sweep, 3)
-            .map(|w| w.iter().cloned().sum::<isize>())
+        let windowed_sum = Windowed::new(sonar_sweep.iter().copied(), 3)
+            .map(|w| w.iter().sum::<isize>())
             .collect::<Vec<_>>();
 
         assert_eq!(windowed_sum, vec![607, 618, 618, 617, 647, 716, 769, 792]);
     }
+
+    #[test]
+    fn sliding() {
+        assert_eq!(
+            windows(Windowed::new(1..=5, 3)),
+            vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]
+        );
+        assert_eq!(
+            windows(Windowed::new(1..=5, 3).partial()),
+            vec![
+                vec![1, 2, 3],
+                vec![2, 3, 4],
+                vec![3, 4, 5],
+                vec![4, 5],
+                vec![5]
+            ]
+        );
+        assert!(windows(Windowed::new(1..=2, 3)).is_empty());
+    }
+
+    #[test]
+    fn tumbling() {
+        assert_eq!(
+            windows(Windowed::tumbling(1..=5, 2)),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+        assert_eq!(
+            windows(Windowed::tumbling(1..=5, 2).partial()),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn step() {
+        assert_eq!(
+            windows(Windowed::new(1..=7, 3).step(2)),
+            vec![vec![1, 2, 3], vec![3, 4, 5], vec![5, 6, 7]]
+        );
+        assert_eq!(
+            windows(Windowed::new(1..=8, 2).step(3).partial()),
+            vec![vec![1, 2], vec![4, 5], vec![7, 8]]
+        );
+        assert_eq!(
+            windows(Windowed::new(1..=9, 2).step(3).partial()),
+            vec![vec![1, 2], vec![4, 5], vec![7, 8]]
+        );
+    }
+
+    #[test]
+    fn unbounded() {
+        let sums = Windowed::new(1.., 2).map(|w| w[0] + w[1]).take(3);
+
+        assert_eq!(sums.collect::<Vec<usize>>(), vec![3, 5, 7]);
+    }
 }
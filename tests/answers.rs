@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use aoc_2021::answers::{Answers, Outcome};
+use aoc_2021::input::Input;
+use aoc_2021::solver::{Part, PUZZLES};
+
+#[test]
+fn fixtures_match_recorded_answers() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let answers = Answers::load(&Input::file(fixtures.join("answers.toml"))).expect("no answers");
+    let mut regressions = vec![];
+
+    for puzzle in &PUZZLES {
+        let input = Input::file(fixtures.join(format!("{:02}.txt", puzzle.day)));
+        let lines = input.lines().expect("no fixture");
+        let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+
+        for part in Part::ALL {
+            let check = answers.check(puzzle, &lines, part);
+
+            match check.outcome {
+                Outcome::Correct => {}
+                Outcome::Regressed { expected, actual } => regressions.push(format!(
+                    "day {:02} part {}: expected {:?}, got {:?}",
+                    check.day, part, expected, actual
+                )),
+                Outcome::Unrecorded(_) => regressions.push(format!(
+                    "day {:02} part {}: no recorded answer",
+                    check.day, part
+                )),
+            }
+        }
+    }
+
+    assert!(regressions.is_empty(), "\n{}", regressions.join("\n"));
+}
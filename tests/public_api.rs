@@ -0,0 +1,101 @@
+use aoc_2021::day01::{count_increases, Day01};
+use aoc_2021::day05::{VentDiagram, VentLine};
+use aoc_2021::day12::{traverse, CaveSystem, ComplexLimiter, SimpleLimiter};
+use aoc_2021::day14::{parse_polymerization, polymer_strength};
+use aoc_2021::day15::Maze;
+use aoc_2021::day16::{Packet, Transmission};
+use aoc_2021::day17::{count_solutions, highest_possible_y, Rect};
+use aoc_2021::day18::Pair;
+use aoc_2021::input::Input;
+use aoc_2021::solver::{solve, Part};
+
+#[test]
+fn day01_count_increases() {
+    let sonar_sweep = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    assert_eq!(count_increases(sonar_sweep.iter()), Ok(7));
+}
+
+#[test]
+fn day05_vent_diagram() {
+    let mut diagram = VentDiagram::with_capacity(0);
+
+    for line in &["0,9 -> 5,9", "0,9 -> 2,9", "3,4 -> 1,4"] {
+        let line = line.parse::<VentLine>().expect("bad vent line");
+        diagram.push(&line).expect("too many points");
+    }
+
+    assert_eq!(diagram.num_overlapping(), 3);
+}
+
+#[test]
+fn day12_cave_system() {
+    let caves = ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
+    let cave_system = CaveSystem::parse(&caves).expect("no caves");
+
+    assert_eq!(cave_system.len(), 6);
+    assert_eq!(traverse(&cave_system, SimpleLimiter::new()).len(), 10);
+    assert_eq!(traverse(&cave_system, ComplexLimiter::new()).len(), 36);
+}
+
+#[test]
+fn day14_polymer() {
+    let lines = [
+        "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B", "HN -> C",
+        "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B", "CC -> N",
+        "CN -> C",
+    ];
+    let (polymer, rules) = parse_polymerization(&lines).expect("bad polymer");
+
+    assert_eq!(polymer_strength(&polymer, &rules, 10), 1588);
+}
+
+#[test]
+fn day15_maze() {
+    let maze = Maze::parse(&["19", "11"]).expect("no maze");
+
+    assert_eq!(maze.shortest_path_tb(), Some(2));
+}
+
+#[test]
+fn day16_transmission() {
+    let mut transmission = "D2FE28"
+        .parse::<Transmission>()
+        .expect("not a transmission");
+
+    assert_eq!(
+        transmission.consume_packet(),
+        Some(Packet::Literal {
+            version: 6,
+            value: 2021
+        })
+    );
+}
+
+#[test]
+fn day17_target_area() {
+    let target_area = Rect::new((20, 30), (-10, -5));
+
+    assert_eq!(highest_possible_y(&target_area), Some(45));
+    assert_eq!(count_solutions(&target_area), 112);
+}
+
+#[test]
+fn day18_pair() {
+    let lhs = "[[[[4,3],4],4],[7,[[8,4],9]]]"
+        .parse::<Pair>()
+        .expect("not a pair");
+    let rhs = "[1,1]".parse::<Pair>().expect("not a pair");
+
+    assert_eq!((lhs + rhs).magnitude(), 1384);
+}
+
+#[test]
+fn input_from_memory() {
+    let input = Input::memory("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n");
+    let lines = input.lines().expect("no lines");
+    let lines = lines.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(solve::<Day01>(&lines, Part::One), Ok("7".to_string()));
+    assert_eq!(solve::<Day01>(&lines, Part::Two), Ok("5".to_string()));
+}